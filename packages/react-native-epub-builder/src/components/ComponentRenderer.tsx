@@ -1,11 +1,30 @@
 import type React from 'react';
-import type { ImageStyle, TextStyle, ViewStyle } from 'react-native';
-import { Image, ScrollView, Text, View } from 'react-native';
-import type { RnNode, RnStyles } from 'react-native-epub-json';
+import type {
+  ImageStyle,
+  NativeMethods,
+  TextStyle,
+  ViewStyle,
+} from 'react-native';
+import { Image, Linking, ScrollView, Text, View } from 'react-native';
+import type {
+  LinkTarget,
+  ListItemNode,
+  RnNode,
+  RnStyles,
+  TableColumn,
+  TableNode,
+  TableRowNode,
+} from 'react-native-epub-json';
 
 interface ComponentRendererProps {
   node: RnNode;
   renderCustomComponent?: (node: RnNode) => React.ReactElement | null;
+  onLinkPress?: (target: LinkTarget) => void;
+  /** Called for note references; falls back to `onLinkPress` when absent. */
+  onNoteRefPress?: (target: LinkTarget) => void;
+  onAnchorRef?: (id: string, instance: NativeMethods | null) => void;
+  /** Image sources by image id, for books converted in `reference` mode. */
+  images?: Record<string, string>;
 }
 
 const convertToReactNativeStyle = (
@@ -37,9 +56,29 @@ const convertToReactNativeStyle = (
   return converted;
 };
 
+// Widest a column without an explicit width grows before its text wraps
+const MAX_COLUMN_WIDTH = 240;
+
+const tableColumnWidths = (columns: TableColumn[]): number[] =>
+  columns.map((column) =>
+    typeof column.width === 'number'
+      ? column.width
+      : Math.max(
+          column.min_width,
+          Math.min(column.max_width, MAX_COLUMN_WIDTH),
+        ),
+  );
+
+const spanWidth = (widths: number[], start: number, count: number) =>
+  widths.slice(start, start + count).reduce((sum, width) => sum + width, 0);
+
 export const ComponentRenderer: React.FC<ComponentRendererProps> = ({
   node,
   renderCustomComponent,
+  onLinkPress,
+  onNoteRefPress,
+  onAnchorRef,
+  images,
 }) => {
   if (renderCustomComponent) {
     const customComponent = renderCustomComponent(node);
@@ -48,28 +87,141 @@ export const ComponentRenderer: React.FC<ComponentRendererProps> = ({
     }
   }
 
+  const handleLinkPress = (target: LinkTarget) => {
+    if (onLinkPress) {
+      onLinkPress(target);
+    } else if (target.kind !== 'Internal') {
+      Linking.openURL(target.url);
+    }
+  };
+
+  // Nodes with an HTML id report their instance so the reader can scroll to them
+  const anchorRef = (id: string | undefined) =>
+    id && onAnchorRef
+      ? (instance: NativeMethods | null) => onAnchorRef(id, instance)
+      : undefined;
+
+  // Marker in a fixed-width column so wrapped lines hang past it
+  const renderListItem = (
+    item: ListItemNode,
+    index: number,
+    indent: number,
+  ): React.ReactElement => (
+    <View
+      key={index}
+      ref={anchorRef(item.id)}
+      style={[{ flexDirection: 'row' }, convertToReactNativeStyle(item.styles)]}
+    >
+      <Text
+        style={[
+          convertToReactNativeStyle(item.marker_styles),
+          { width: indent },
+        ]}
+      >
+        {item.marker}
+      </Text>
+      <View style={{ flex: 1 }}>{item.children?.map(renderNode)}</View>
+    </View>
+  );
+
+  const renderTableRow = (
+    row: TableRowNode,
+    index: number,
+    widths: number[],
+  ): React.ReactElement => {
+    const cells: React.ReactElement[] = [];
+    let column = 0;
+    row.children?.forEach((cell, cellIndex) => {
+      if (cell.type !== 'TableCell') return;
+      if (cell.column > column) {
+        // Columns covered by a rowspan from an earlier row
+        const gap = spanWidth(widths, column, cell.column - column);
+        cells.push(<View key={`gap-${cellIndex}`} style={{ width: gap }} />);
+      }
+      cells.push(
+        <View
+          key={cellIndex}
+          ref={anchorRef(cell.id)}
+          style={[
+            convertToReactNativeStyle(cell.styles),
+            { width: spanWidth(widths, cell.column, cell.colspan) },
+          ]}
+        >
+          {cell.children?.map(renderNode)}
+        </View>,
+      );
+      column = cell.column + cell.colspan;
+    });
+
+    return (
+      <View
+        key={index}
+        ref={anchorRef(row.id)}
+        style={[
+          { flexDirection: 'row' },
+          convertToReactNativeStyle(row.styles),
+        ]}
+      >
+        {cells}
+      </View>
+    );
+  };
+
+  // Wide tables scroll horizontally instead of squeezing their columns
+  const renderTable = (table: TableNode, index: number) => {
+    const widths = tableColumnWidths(table.columns);
+    return (
+      <ScrollView
+        key={index}
+        ref={anchorRef(table.id)}
+        horizontal
+        style={convertToReactNativeStyle(table.styles)}
+      >
+        <View>
+          {table.children?.map((child, childIndex) =>
+            child.type === 'TableRow'
+              ? renderTableRow(child, childIndex, widths)
+              : renderNode(child, childIndex),
+          )}
+        </View>
+      </ScrollView>
+    );
+  };
+
   const renderNode = (currentNode: RnNode, index: number) => {
     const { type } = currentNode;
     const convertedStyles = convertToReactNativeStyle(currentNode.styles);
+    const ref = anchorRef(currentNode.id);
 
     switch (type) {
       case 'Text':
         return (
-          <Text key={index} style={convertedStyles}>
+          <Text key={index} ref={ref} style={convertedStyles}>
             {currentNode.content}
           </Text>
         );
 
       case 'View':
+      case 'TableCaption':
+      case 'TableRow':
+      case 'TableCell':
         return (
-          <View key={index} style={convertedStyles}>
+          <View key={index} ref={ref} style={convertedStyles}>
             {currentNode.children?.map(renderNode)}
           </View>
         );
 
+      case 'Span':
+        // Nested Text so mixed formatting wraps as a single paragraph
+        return (
+          <Text key={index} ref={ref} style={convertedStyles}>
+            {currentNode.children?.map(renderNode)}
+          </Text>
+        );
+
       case 'ScrollView':
         return (
-          <ScrollView key={index} style={convertedStyles}>
+          <ScrollView key={index} ref={ref} style={convertedStyles}>
             {currentNode.children?.map(renderNode)}
           </ScrollView>
         );
@@ -78,12 +230,66 @@ export const ComponentRenderer: React.FC<ComponentRendererProps> = ({
         return (
           <Image
             key={index}
-            source={{ uri: currentNode.source }}
-            style={convertedStyles}
+            ref={ref}
+            source={{ uri: images?.[currentNode.source] ?? currentNode.source }}
+            style={[
+              {
+                width: currentNode.width ?? undefined,
+                aspectRatio: currentNode.aspect_ratio ?? undefined,
+                maxWidth: '100%',
+              },
+              convertedStyles,
+            ]}
             resizeMode="contain"
           />
         );
 
+      case 'List':
+        return (
+          <View key={index} ref={ref} style={convertedStyles}>
+            {currentNode.children?.map((child, childIndex) =>
+              child.type === 'ListItem'
+                ? renderListItem(child, childIndex, currentNode.indent)
+                : renderNode(child, childIndex),
+            )}
+          </View>
+        );
+
+      case 'ListItem':
+        // An item outside of a list still gets a marker column
+        return renderListItem(currentNode, index, 24);
+
+      case 'Table':
+        return renderTable(currentNode, index);
+
+      case 'Link':
+        return (
+          <Text
+            key={index}
+            ref={ref}
+            style={convertedStyles}
+            onPress={() => handleLinkPress(currentNode.target)}
+          >
+            {currentNode.children?.map(renderNode)}
+          </Text>
+        );
+
+      case 'NoteRef':
+        return (
+          <Text
+            key={index}
+            ref={ref}
+            style={convertedStyles}
+            onPress={() =>
+              onNoteRefPress
+                ? onNoteRefPress(currentNode.target)
+                : handleLinkPress(currentNode.target)
+            }
+          >
+            {currentNode.children?.map(renderNode)}
+          </Text>
+        );
+
       default:
         return <View key={index} style={convertedStyles} />;
     }
@@ -20,10 +20,12 @@ export const EPUBPage: React.FC<EPUBPageProps> = ({
 }) => {
   const [currentChapterIndex, setCurrentChapterIndex] =
     useState(initialChapter);
+  const [anchor, setAnchor] = useState<string | undefined>();
   const [showTOCModal, setShowTOCModal] = useState(false);
 
-  const handleChapterChange = (newIndex: number) => {
+  const handleChapterChange = (newIndex: number, newAnchor?: string) => {
     setCurrentChapterIndex(newIndex);
+    setAnchor(newAnchor);
     onTOCPress?.(newIndex);
   };
 
@@ -31,8 +33,9 @@ export const EPUBPage: React.FC<EPUBPageProps> = ({
     setShowTOCModal(true);
   };
 
-  const handleTOCItemPress = (chapterIndex: number) => {
+  const handleTOCItemPress = (chapterIndex: number, tocAnchor?: string) => {
     setCurrentChapterIndex(chapterIndex);
+    setAnchor(tocAnchor);
     onTOCPress?.(chapterIndex);
   };
 
@@ -52,6 +55,8 @@ export const EPUBPage: React.FC<EPUBPageProps> = ({
       <EPUBReader
         data={data}
         style={styles.reader}
+        chapterIndex={currentChapterIndex}
+        anchor={anchor}
         onChapterChange={handleChapterChange}
       />
 
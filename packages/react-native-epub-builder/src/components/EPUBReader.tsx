@@ -1,20 +1,115 @@
 import type React from 'react';
-import { useMemo, useState } from 'react';
-import { ScrollView, StyleSheet, Text, View } from 'react-native';
+import { useEffect, useMemo, useRef, useState } from 'react';
+import type { NativeMethods } from 'react-native';
+import {
+  Linking,
+  Modal,
+  Pressable,
+  ScrollView,
+  StyleSheet,
+  Text,
+  View,
+} from 'react-native';
+import type { LinkTarget, Note } from 'react-native-epub-json';
 import type { EPUBReaderProps } from '../types';
 import { ComponentRenderer } from './ComponentRenderer';
 
 export const EPUBReader: React.FC<EPUBReaderProps> = ({
   data,
   style,
+  chapterIndex,
+  anchor,
+  onChapterChange,
   renderCustomComponent,
 }) => {
-  const [currentChapterIndex, _setCurrentChapterIndex] = useState(0);
+  const [currentChapterIndex, setCurrentChapterIndex] = useState(
+    chapterIndex ?? 0,
+  );
+  const scrollViewRef = useRef<ScrollView>(null);
+  const contentRef = useRef<View>(null);
+  const anchorRefs = useRef(new Map<string, NativeMethods>());
+  const [openNote, setOpenNote] = useState<Note | null>(null);
+  // Anchor to scroll to once the chapter content has been laid out
+  const pendingAnchor = useRef<string | undefined>(anchor);
 
   const currentChapter = useMemo(() => {
     return data.chapters[currentChapterIndex];
   }, [data.chapters, currentChapterIndex]);
 
+  const scrollToPendingAnchor = () => {
+    const id = pendingAnchor.current;
+    const target = id ? anchorRefs.current.get(id) : undefined;
+    if (!target || !contentRef.current) {
+      return;
+    }
+    target.measureLayout(
+      contentRef.current,
+      (_x, y) => {
+        scrollViewRef.current?.scrollTo({ y, animated: false });
+        pendingAnchor.current = undefined;
+      },
+      () => {},
+    );
+  };
+
+  const goTo = (index: number, id?: string) => {
+    pendingAnchor.current = id;
+    if (index === currentChapterIndex) {
+      if (id) {
+        scrollToPendingAnchor();
+      }
+    } else {
+      setCurrentChapterIndex(index);
+    }
+    if (!id) {
+      scrollViewRef.current?.scrollTo({ y: 0, animated: false });
+    }
+  };
+
+  // biome-ignore lint/correctness/useExhaustiveDependencies: only follow prop changes
+  useEffect(() => {
+    if (chapterIndex !== undefined) {
+      goTo(chapterIndex, anchor);
+    }
+  }, [chapterIndex, anchor]);
+
+  const handleLinkPress = (target: LinkTarget) => {
+    if (target.kind !== 'Internal') {
+      Linking.openURL(target.url);
+      return;
+    }
+    const index = data.chapters.findIndex(
+      (chapter) => chapter.spine_index === target.spine_index,
+    );
+    if (index !== -1) {
+      goTo(index, target.fragment);
+      onChapterChange?.(index, target.fragment);
+    }
+  };
+
+  // Show the note in a popover; notes that weren't extracted are followed
+  const handleNoteRefPress = (target: LinkTarget) => {
+    const note =
+      target.kind === 'Internal'
+        ? data.chapters
+            .find((chapter) => chapter.spine_index === target.spine_index)
+            ?.notes.find((candidate) => candidate.id === target.fragment)
+        : undefined;
+    if (note) {
+      setOpenNote(note);
+    } else {
+      handleLinkPress(target);
+    }
+  };
+
+  const handleAnchorRef = (id: string, instance: NativeMethods | null) => {
+    if (instance) {
+      anchorRefs.current.set(id, instance);
+    } else {
+      anchorRefs.current.delete(id);
+    }
+  };
+
   if (!currentChapter) {
     return (
       <View style={[styles.container, style]}>
@@ -26,14 +121,50 @@ export const EPUBReader: React.FC<EPUBReaderProps> = ({
   return (
     <View style={[styles.container, style]}>
       <ScrollView
+        ref={scrollViewRef}
         style={styles.scrollView}
         contentContainerStyle={styles.contentContainer}
+        onContentSizeChange={scrollToPendingAnchor}
       >
-        <ComponentRenderer
-          node={currentChapter.content}
-          renderCustomComponent={renderCustomComponent}
-        />
+        <View ref={contentRef} collapsable={false}>
+          <ComponentRenderer
+            node={currentChapter.content}
+            renderCustomComponent={renderCustomComponent}
+            onLinkPress={handleLinkPress}
+            onNoteRefPress={handleNoteRefPress}
+            onAnchorRef={handleAnchorRef}
+            images={data.images}
+          />
+        </View>
       </ScrollView>
+      <Modal
+        visible={openNote !== null}
+        transparent
+        animationType="fade"
+        onRequestClose={() => setOpenNote(null)}
+      >
+        <View style={styles.noteBackdrop}>
+          <Pressable
+            style={StyleSheet.absoluteFill}
+            onPress={() => setOpenNote(null)}
+          />
+          <View style={styles.notePopover}>
+            <ScrollView>
+              {openNote && (
+                <ComponentRenderer
+                  node={openNote.content}
+                  renderCustomComponent={renderCustomComponent}
+                  onLinkPress={(target) => {
+                    setOpenNote(null);
+                    handleLinkPress(target);
+                  }}
+                  images={data.images}
+                />
+              )}
+            </ScrollView>
+          </View>
+        </View>
+      </Modal>
     </View>
   );
 };
@@ -48,6 +179,18 @@ const styles = StyleSheet.create({
   contentContainer: {
     padding: 16,
   },
+  noteBackdrop: {
+    flex: 1,
+    justifyContent: 'flex-end',
+    backgroundColor: 'rgba(0, 0, 0, 0.3)',
+  },
+  notePopover: {
+    maxHeight: '50%',
+    padding: 16,
+    borderTopLeftRadius: 12,
+    borderTopRightRadius: 12,
+    backgroundColor: '#fff',
+  },
   errorText: {
     textAlign: 'center',
     fontSize: 16,
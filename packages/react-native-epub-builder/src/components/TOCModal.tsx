@@ -8,16 +8,20 @@ import {
   TouchableOpacity,
   View,
 } from 'react-native';
-import type { CompleteEpubInfo } from 'react-native-epub-json';
+import type { CompleteEpubInfo, TocItem } from 'react-native-epub-json';
 
 interface TOCModalProps {
   visible: boolean;
   data: CompleteEpubInfo;
   onClose: () => void;
-  onItemPress: (chapterIndex: number) => void;
+  onItemPress: (chapterIndex: number, anchor?: string) => void;
   currentChapterIndex: number;
 }
 
+// Nested entries are listed in reading order and indented by depth
+const flattenToc = (items: TocItem[]): TocItem[] =>
+  items.flatMap((item) => [item, ...flattenToc(item.children)]);
+
 export const TOCModal: React.FC<TOCModalProps> = ({
   visible,
   data,
@@ -25,24 +29,28 @@ export const TOCModal: React.FC<TOCModalProps> = ({
   onItemPress,
   currentChapterIndex,
 }) => {
-  const renderTOCItem = ({
-    item,
-    index,
-  }: {
-    item: (typeof data.toc)[0];
-    index: number;
-  }) => {
-    const isActive = index === currentChapterIndex;
+  const renderTOCItem = ({ item }: { item: TocItem }) => {
+    const { chapter_index: chapterIndex, anchor } = item;
+    const isActive = chapterIndex === currentChapterIndex;
 
     return (
       <TouchableOpacity
         style={[styles.tocItem, isActive && styles.activeTocItem]}
+        disabled={chapterIndex === undefined}
         onPress={() => {
-          onItemPress(index);
+          if (chapterIndex !== undefined) {
+            onItemPress(chapterIndex, anchor);
+          }
           onClose();
         }}
       >
-        <Text style={[styles.tocLabel, isActive && styles.activeTocLabel]}>
+        <Text
+          style={[
+            styles.tocLabel,
+            { paddingLeft: item.depth * 16 },
+            isActive && styles.activeTocLabel,
+          ]}
+        >
           {item.label}
         </Text>
       </TouchableOpacity>
@@ -65,9 +73,9 @@ export const TOCModal: React.FC<TOCModalProps> = ({
         </View>
 
         <FlatList
-          data={data.toc}
+          data={flattenToc(data.toc)}
           renderItem={renderTOCItem}
-          keyExtractor={(_, index) => index.toString()}
+          keyExtractor={(item, index) => `${item.play_order}-${index}`}
           style={styles.list}
         />
       </SafeAreaView>
@@ -1,14 +1,36 @@
 // Re-export types from react-native-epub-json for convenience
 export type {
   ChapterStructure,
+  Collection,
   CompleteEpubInfo,
+  Contributor,
+  CoverImage,
   EpubMetadata,
   EpubStructure,
+  EpubSummary,
+  ImageAsset,
   ImageNode,
+  Landmark,
+  LinkNode,
+  LinkTarget,
+  ListItemNode,
+  ListNode,
+  MetadataIdentifier,
+  MetadataTitle,
+  Note,
+  NoteKind,
+  NoteRefNode,
+  PageTarget,
   RnNode,
   RnStyles,
   ScrollViewNode,
+  SpanNode,
   SpineItemInfo,
+  TableCaptionNode,
+  TableCellNode,
+  TableColumn,
+  TableNode,
+  TableRowNode,
   TextNode,
   TocItem,
   ViewNode,
@@ -4,7 +4,11 @@ import type { CompleteEpubInfo, RnNode } from 'react-native-epub-json';
 export interface EPUBReaderProps {
   data: CompleteEpubInfo;
   style?: ViewStyle;
-  onChapterChange?: (chapterIndex: number) => void;
+  /** Chapter to display; the reader follows changes to this prop. */
+  chapterIndex?: number;
+  /** Element id within the chapter to scroll to. */
+  anchor?: string;
+  onChapterChange?: (chapterIndex: number, anchor?: string) => void;
   renderCustomComponent?: (node: RnNode) => React.ReactElement | null;
 }
 
@@ -0,0 +1,320 @@
+use scraper::ElementRef;
+use crate::types::RnStyles;
+use crate::color::CURRENT_COLOR;
+use crate::lists::list_type_attribute;
+use crate::css_parser::{apply_css_declarations, merge_styles, parse_css_declarations, CssDeclaration, CssOrigin, CssRule, LengthContext, Stylesheet};
+
+/// 요소 하나에 대한 CSS 캐스케이드를 수행해 계산된 스타일을 반환
+///
+/// 우선순위 (낮음 → 높음):
+/// 1. user agent 선언
+/// 2. HTML 속성의 표현 힌트 (`<ol type>` 등, 명시도 0인 저자 선언으로 취급)
+/// 3. 저자(author) 선언 — 명시도, 같으면 소스 순서
+/// 4. 인라인 `style` 속성 선언
+/// 5. 저자 `!important` 선언 — 명시도, 같으면 소스 순서
+/// 6. 인라인 `!important` 선언
+///
+/// 속성 단위로 캐스케이드되므로, 같은 선택자의 규칙이 여러 개여도
+/// 뒤 규칙에 없는 속성은 앞 규칙의 값이 유지됩니다.
+///
+/// `context`의 `parent_font_size`는 부모 요소의 폰트 크기여야 합니다.
+/// em 등 폰트 크기에 의존하는 단위는 이 요소의 최종 폰트 크기를 기준으로 계산됩니다.
+pub fn compute_element_style(
+    element: ElementRef,
+    stylesheet: &Stylesheet,
+    fallback: Option<RnStyles>,
+    context: &LengthContext,
+) -> Option<RnStyles> {
+    let mut matched: Vec<_> = stylesheet.matching_rules(&element).collect();
+    matched.sort_by_key(|(order, rule)| (rule.origin, rule.selector.specificity(), *order));
+    let matched: Vec<&CssRule> = matched.into_iter().map(|(_, rule)| rule).collect();
+
+    let inline_declarations = element.value().attr("style")
+        .map(parse_css_declarations)
+        .unwrap_or_default();
+    let hints = presentational_hints(element);
+
+    // 1차: 이 요소의 폰트 크기 결정 (font-size의 em/%는 부모 기준이므로 문맥과 무관)
+    let style = cascade(&matched, &hints, &inline_declarations, fallback.clone(), context);
+    let font_size = style.fontSize.unwrap_or(context.parent_font_size);
+
+    // 2차: 폰트 크기가 바뀌었으면 그 크기를 기준으로 나머지 단위를 다시 계산
+    let style = if font_size != context.font_size {
+        cascade(&matched, &hints, &inline_declarations, fallback, &LengthContext { font_size, ..*context })
+    } else {
+        style
+    };
+
+    if style == RnStyles::default() {
+        None
+    } else {
+        Some(style)
+    }
+}
+
+fn cascade(
+    matched: &[&CssRule],
+    hints: &[CssDeclaration],
+    inline_declarations: &[CssDeclaration],
+    fallback: Option<RnStyles>,
+    context: &LengthContext,
+) -> RnStyles {
+    let has_author_rules = matched.iter().any(|rule| rule.origin == CssOrigin::Author);
+    let mut style = RnStyles::default();
+
+    // 1~3. 일반 선언 (user agent → 표현 힌트 → 저자)
+    for rule in matched.iter().filter(|rule| rule.origin == CssOrigin::UserAgent) {
+        apply_css_declarations(&mut style, normal(&rule.declarations), context);
+    }
+    // 저자 규칙이 하나도 매칭되지 않으면 클래스명 기반 기본 스타일을 user agent 위에 적용
+    if !has_author_rules && let Some(fallback) = fallback {
+        style = merge_styles(Some(style), Some(fallback)).unwrap_or_default();
+    }
+    apply_css_declarations(&mut style, hints.iter(), context);
+    for rule in matched.iter().filter(|rule| rule.origin == CssOrigin::Author) {
+        apply_css_declarations(&mut style, normal(&rule.declarations), context);
+    }
+
+    // 4. 인라인 선언
+    apply_css_declarations(&mut style, normal(inline_declarations), context);
+
+    // 5~6. !important 선언 (저자 → 인라인)
+    for rule in matched.iter().filter(|rule| rule.origin == CssOrigin::Author) {
+        apply_css_declarations(&mut style, important(&rule.declarations), context);
+    }
+    apply_css_declarations(&mut style, important(inline_declarations), context);
+
+    style
+}
+
+/// HTML 속성을 CSS 선언으로 옮긴 표현 힌트
+fn presentational_hints(element: ElementRef) -> Vec<CssDeclaration> {
+    list_type_attribute(element)
+        .map(|list_style_type| CssDeclaration {
+            property: "list-style-type".to_string(),
+            value: list_style_type.to_string(),
+            important: false,
+        })
+        .into_iter()
+        .collect()
+}
+
+fn normal(declarations: &[CssDeclaration]) -> impl Iterator<Item = &CssDeclaration> {
+    declarations.iter().filter(|declaration| !declaration.important)
+}
+
+fn important(declarations: &[CssDeclaration]) -> impl Iterator<Item = &CssDeclaration> {
+    declarations.iter().filter(|declaration| declaration.important)
+}
+
+/// 상속되는 텍스트 속성이 비어 있으면 부모 스타일의 값으로 채움
+///
+/// `textDecorationLine`은 CSS에서 상속 속성은 아니지만 자손 텍스트에 전파되므로 함께 처리합니다.
+pub fn inherit_text_styles(mut style: RnStyles, parent: &RnStyles) -> RnStyles {
+    if style.color.is_none() { style.color = parent.color.clone(); }
+    if style.fontFamily.is_none() { style.fontFamily = parent.fontFamily.clone(); }
+    if style.fontSize.is_none() { style.fontSize = parent.fontSize; }
+    if style.fontStyle.is_none() { style.fontStyle = parent.fontStyle.clone(); }
+    if style.fontWeight.is_none() { style.fontWeight = parent.fontWeight.clone(); }
+    if style.lineHeight.is_none() {
+        // 단위 없는 line-height는 배수로 상속되어 자식의 폰트 크기로 다시 계산됨
+        style.lineHeightMultiplier = parent.lineHeightMultiplier;
+        style.lineHeight = match (parent.lineHeightMultiplier, style.fontSize) {
+            (Some(multiplier), Some(font_size)) => Some(multiplier * font_size),
+            _ => parent.lineHeight,
+        };
+    }
+    if style.textAlign.is_none() { style.textAlign = parent.textAlign.clone(); }
+    if style.textTransform.is_none() { style.textTransform = parent.textTransform.clone(); }
+    if style.textDecorationLine.is_none() { style.textDecorationLine = parent.textDecorationLine.clone(); }
+    let color = style.color.clone();
+    resolve_current_color(&mut style, color.as_deref());
+    style
+}
+
+/// `currentColor`로 지정된 배경/테두리 색상을 요소의 최종 `color`로 치환
+///
+/// `color`가 없으면 React Native 기본값(검정)을 따르도록 값을 비웁니다.
+pub fn resolve_current_color(style: &mut RnStyles, color: Option<&str>) {
+    for field in [
+        &mut style.backgroundColor,
+        &mut style.borderColor,
+        &mut style.borderTopColor,
+        &mut style.borderBottomColor,
+        &mut style.borderLeftColor,
+        &mut style.borderRightColor,
+    ] {
+        if field.as_deref() == Some(CURRENT_COLOR) {
+            *field = color.map(|color| color.to_string());
+        }
+    }
+}
+
+/// 상속되는 텍스트 속성만 추출 (Text 노드용)
+pub fn text_styles(style: &RnStyles) -> Option<RnStyles> {
+    let text_style = inherit_text_styles(RnStyles::default(), style);
+    if text_style == RnStyles::default() {
+        None
+    } else {
+        Some(text_style)
+    }
+}
+
+/// 인라인 텍스트에 적용할 속성만 추출 (서식 요소가 텍스트 하나만 감싸 Text로 합칠 때)
+///
+/// 상속된 텍스트 속성과 요소 자신의 배경색만 남기고, 여백이나 크기 같은 박스 속성은 뺍니다.
+pub fn inline_text_styles(style: &RnStyles) -> Option<RnStyles> {
+    let inline_style = RnStyles {
+        color: style.color.clone(),
+        fontFamily: style.fontFamily.clone(),
+        fontSize: style.fontSize,
+        fontStyle: style.fontStyle.clone(),
+        fontWeight: style.fontWeight.clone(),
+        textDecorationLine: style.textDecorationLine.clone(),
+        textTransform: style.textTransform.clone(),
+        lineHeight: style.lineHeight,
+        lineHeightMultiplier: style.lineHeightMultiplier,
+        backgroundColor: style.backgroundColor.clone(),
+        ..Default::default()
+    };
+    if inline_style == RnStyles::default() {
+        None
+    } else {
+        Some(inline_style)
+    }
+}
+
+/// `inherit`/`unset` 값 처리: 상속 속성이면 값을 비워 부모 값을 물려받도록 함
+pub fn reset_inherited_property(style: &mut RnStyles, property: &str) {
+    match property {
+        "color" => style.color = None,
+        "font-family" => style.fontFamily = None,
+        "font-size" => style.fontSize = None,
+        "font-style" => style.fontStyle = None,
+        "font-weight" => style.fontWeight = None,
+        "line-height" => {
+            style.lineHeight = None;
+            style.lineHeightMultiplier = None;
+        }
+        "text-align" => style.textAlign = None,
+        "text-transform" => style.textTransform = None,
+        "list-style-type" => style.listStyleType = None,
+        "white-space" => style.whiteSpace = None,
+        _ => {} // 상속되지 않는 속성의 inherit는 지원하지 않음
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scraper::{Html, Selector};
+    use crate::types::RnDimension;
+
+    /// 루트(16px)부터 `selector` 요소까지 차례로 캐스케이드하고 상속을 채운 스타일
+    fn resolved_style(body: &str, css: &str, selector: &str) -> RnStyles {
+        let document = Html::parse_document(&format!("<html><body>{body}</body></html>"));
+        let stylesheet = Stylesheet::parse(css);
+        let target = document.select(&Selector::parse(selector).unwrap()).next().unwrap();
+        let mut chain: Vec<ElementRef> = target.ancestors().filter_map(ElementRef::wrap).collect();
+        chain.reverse();
+        chain.push(target);
+
+        let root = LengthContext::default();
+        let mut style = RnStyles { fontSize: Some(root.root_font_size), ..Default::default() };
+        for element in chain {
+            let context = root.for_child(style.fontSize.unwrap());
+            let computed = compute_element_style(element, &stylesheet, None, &context).unwrap_or_default();
+            style = inherit_text_styles(computed, &style);
+        }
+        style
+    }
+
+    #[test]
+    fn nested_em_font_sizes_compound() {
+        let css = "div { font-size: 2em } p { font-size: 1.5em } span { font-size: 50% }";
+        let body = "<div><p><span>x</span></p></div>";
+        assert_eq!(resolved_style(body, css, "div").fontSize, Some(32.0));
+        assert_eq!(resolved_style(body, css, "p").fontSize, Some(48.0));
+        assert_eq!(resolved_style(body, css, "span").fontSize, Some(24.0));
+    }
+
+    #[test]
+    fn rem_resolves_against_root_font_size() {
+        let css = "div { font-size: 3em } p { font-size: 2rem; margin-top: 1rem; padding-left: 1em }";
+        let style = resolved_style("<div><p>x</p></div>", css, "p");
+        assert_eq!(style.fontSize, Some(32.0));
+        assert_eq!(style.marginTop, Some(RnDimension::Points(16.0)));
+        assert_eq!(style.paddingLeft, Some(RnDimension::Points(32.0)));
+    }
+
+    #[test]
+    fn em_lengths_use_the_element_font_size_regardless_of_order() {
+        // margin이 font-size보다 먼저 선언되어도 이 요소의 최종 폰트 크기가 기준
+        let css = "p { margin-top: 1em; font-size: 20px } .big { font-size: 2em }";
+        let style = resolved_style(r#"<p class="big">x</p>"#, css, "p");
+        assert_eq!(style.fontSize, Some(32.0));
+        assert_eq!(style.marginTop, Some(RnDimension::Points(32.0)));
+    }
+
+    #[test]
+    fn unitless_line_height_is_inherited_as_a_multiplier() {
+        let css = "div { font-size: 10px; line-height: 1.5 } p { font-size: 20px }";
+        let style = resolved_style("<div><p>x</p></div>", css, "p");
+        assert_eq!(style.lineHeightMultiplier, Some(1.5));
+        assert_eq!(style.lineHeight, Some(30.0));
+    }
+
+    #[test]
+    fn length_line_height_is_inherited_as_computed() {
+        let css = "div { font-size: 10px; line-height: 1.5em } p { font-size: 20px }";
+        let style = resolved_style("<div><p>x</p></div>", css, "p");
+        assert_eq!(style.lineHeightMultiplier, None);
+        assert_eq!(style.lineHeight, Some(15.0));
+    }
+
+    fn color(body: &str, css: &str) -> Option<String> {
+        resolved_style(body, css, "p").color
+    }
+
+    #[test]
+    fn more_specific_selectors_win() {
+        let body = r#"<p id="a" class="b">x</p>"#;
+        assert_eq!(color(body, "#a { color: #ff0000 } .b { color: #00ff00 } p { color: #0000ff }").as_deref(), Some("#ff0000"));
+        assert_eq!(color(body, "p { color: #0000ff } .b { color: #00ff00 }").as_deref(), Some("#00ff00"));
+        // 선언 순서와 무관
+        assert_eq!(color(body, ".b { color: #00ff00 } p { color: #0000ff }").as_deref(), Some("#00ff00"));
+        assert_eq!(color(body, "p.b { color: #ff0000 } .b { color: #00ff00 }").as_deref(), Some("#ff0000"));
+    }
+
+    #[test]
+    fn later_rule_wins_on_equal_specificity() {
+        let body = r#"<p class="b c">x</p>"#;
+        assert_eq!(color(body, ".b { color: #ff0000 } .c { color: #00ff00 }").as_deref(), Some("#00ff00"));
+        assert_eq!(color(body, ".c { color: #00ff00 } .b { color: #ff0000 }").as_deref(), Some("#ff0000"));
+    }
+
+    #[test]
+    fn cascades_per_property() {
+        let style = resolved_style(r#"<p class="b">x</p>"#, ".b { color: #ff0000; font-size: 20px } p.b { color: #00ff00 }", "p");
+        assert_eq!(style.color.as_deref(), Some("#00ff00"));
+        assert_eq!(style.fontSize, Some(20.0));
+    }
+
+    #[test]
+    fn inline_style_beats_author_rules() {
+        let body = r#"<p id="a" style="color: #00ff00">x</p>"#;
+        assert_eq!(color(body, "#a { color: #ff0000 }").as_deref(), Some("#00ff00"));
+    }
+
+    #[test]
+    fn important_declarations_are_ordered_author_then_inline() {
+        // 저자 !important는 인라인 일반 선언을 이김
+        let body = r#"<p class="b" style="color: #00ff00">x</p>"#;
+        assert_eq!(color(body, "p { color: #ff0000 !important }").as_deref(), Some("#ff0000"));
+        // !important끼리는 명시도 순
+        assert_eq!(color(body, ".b { color: #0000ff !important } p { color: #ff0000 !important }").as_deref(), Some("#0000ff"));
+        // 인라인 !important가 가장 우선
+        let body = r#"<p class="b" style="color: #00ff00 !important">x</p>"#;
+        assert_eq!(color(body, ".b { color: #ff0000 !important }").as_deref(), Some("#00ff00"));
+    }
+}
@@ -0,0 +1,238 @@
+/// `currentColor` 값을 나타내는 내부 표기
+///
+/// 요소의 최종 `color`가 정해진 뒤 [`crate::cascade::resolve_current_color`]에서 실제 색상으로 바뀝니다.
+pub const CURRENT_COLOR: &str = "currentcolor";
+
+/// CSS 색상 값을 정규화
+///
+/// - 불투명 색상은 `#rrggbb`, 반투명 색상은 `#rrggbbaa` 소문자 hex로 변환
+/// - `transparent`는 그대로 유지
+/// - `currentColor`는 [`CURRENT_COLOR`]로 반환
+/// - 해석할 수 없는 값은 None
+pub fn normalize_color(value: &str) -> Option<String> {
+    let value = value.trim();
+    let lower = value.to_ascii_lowercase();
+
+    match lower.as_str() {
+        "transparent" => return Some("transparent".to_string()),
+        CURRENT_COLOR => return Some(CURRENT_COLOR.to_string()),
+        _ => {}
+    }
+
+    let rgba = if let Some(hex) = lower.strip_prefix('#') {
+        parse_hex(hex)?
+    } else if let Some((name, arguments)) = lower.split_once('(') {
+        let arguments = arguments.strip_suffix(')')?;
+        match name.trim() {
+            "rgb" | "rgba" => parse_rgb(arguments)?,
+            "hsl" | "hsla" => parse_hsl(arguments)?,
+            _ => return None,
+        }
+    } else {
+        let index = NAMED_COLORS.binary_search_by_key(&lower.as_str(), |(name, _)| name).ok()?;
+        let rgb = NAMED_COLORS[index].1;
+        [(rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8, 255]
+    };
+
+    Some(format_hex(rgba))
+}
+
+fn format_hex([r, g, b, a]: [u8; 4]) -> String {
+    if a == 255 {
+        format!("#{:02x}{:02x}{:02x}", r, g, b)
+    } else {
+        format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+    }
+}
+
+/// `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`
+fn parse_hex(hex: &str) -> Option<[u8; 4]> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digit = |index: usize| u8::from_str_radix(&hex[index..index + 1], 16).ok();
+    let pair = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+
+    match hex.len() {
+        3 | 4 => {
+            let mut rgba = [255; 4];
+            for (index, channel) in rgba.iter_mut().enumerate().take(hex.len()) {
+                *channel = digit(index)? * 17;
+            }
+            Some(rgba)
+        }
+        6 | 8 => {
+            let mut rgba = [255; 4];
+            for (index, channel) in rgba.iter_mut().enumerate().take(hex.len() / 2) {
+                *channel = pair(index * 2)?;
+            }
+            Some(rgba)
+        }
+        _ => None,
+    }
+}
+
+/// 함수 인자 분리: 콤마 구문(`1, 2, 3, 0.5`)과 공백 구문(`1 2 3 / 50%`) 모두 지원
+fn split_arguments(arguments: &str) -> Vec<&str> {
+    arguments.split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+/// 숫자 또는 퍼센트 (`none`은 0)
+fn parse_number_or_percent(value: &str, percent_scale: f32) -> Option<f32> {
+    if value == "none" {
+        return Some(0.0);
+    }
+    match value.strip_suffix('%') {
+        Some(percent) => percent.parse::<f32>().ok().map(|p| p / 100.0 * percent_scale),
+        None => value.parse().ok(),
+    }
+}
+
+/// 알파 값 (생략 시 불투명)
+fn parse_alpha(value: Option<&&str>) -> Option<u8> {
+    match value {
+        Some(value) => parse_number_or_percent(value, 1.0).map(|alpha| (alpha.clamp(0.0, 1.0) * 255.0).round() as u8),
+        None => Some(255),
+    }
+}
+
+fn to_channel(value: f32) -> u8 {
+    value.clamp(0.0, 255.0).round() as u8
+}
+
+/// `rgb()`/`rgba()` 인자
+fn parse_rgb(arguments: &str) -> Option<[u8; 4]> {
+    let parts = split_arguments(arguments);
+    if parts.len() != 3 && parts.len() != 4 {
+        return None;
+    }
+    Some([
+        to_channel(parse_number_or_percent(parts[0], 255.0)?),
+        to_channel(parse_number_or_percent(parts[1], 255.0)?),
+        to_channel(parse_number_or_percent(parts[2], 255.0)?),
+        parse_alpha(parts.get(3))?,
+    ])
+}
+
+/// 색상환 각도 (단위 없는 값은 deg)
+fn parse_hue(value: &str) -> Option<f32> {
+    if value == "none" {
+        return Some(0.0);
+    }
+    let (number, scale) = if let Some(number) = value.strip_suffix("deg") {
+        (number, 1.0)
+    } else if let Some(number) = value.strip_suffix("grad") {
+        (number, 0.9)
+    } else if let Some(number) = value.strip_suffix("rad") {
+        (number, 180.0 / std::f32::consts::PI)
+    } else if let Some(number) = value.strip_suffix("turn") {
+        (number, 360.0)
+    } else {
+        (value, 1.0)
+    };
+    number.parse::<f32>().ok().map(|hue| (hue * scale).rem_euclid(360.0))
+}
+
+/// `hsl()`/`hsla()` 인자
+fn parse_hsl(arguments: &str) -> Option<[u8; 4]> {
+    let parts = split_arguments(arguments);
+    if parts.len() != 3 && parts.len() != 4 {
+        return None;
+    }
+    let hue = parse_hue(parts[0])?;
+    let saturation = parse_number_or_percent(parts[1], 100.0)?.clamp(0.0, 100.0) / 100.0;
+    let lightness = parse_number_or_percent(parts[2], 100.0)?.clamp(0.0, 100.0) / 100.0;
+
+    // CSS Color 4의 hsl → rgb 변환
+    let channel = |n: f32| {
+        let k = (n + hue / 30.0) % 12.0;
+        let a = saturation * lightness.min(1.0 - lightness);
+        to_channel((lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)) * 255.0)
+    };
+
+    Some([channel(0.0), channel(8.0), channel(4.0), parse_alpha(parts.get(3))?])
+}
+
+/// CSS 이름 색상 (이름순 정렬, 이진 탐색용)
+const NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff), ("antiquewhite", 0xfaebd7), ("aqua", 0x00ffff), ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff), ("beige", 0xf5f5dc), ("bisque", 0xffe4c4), ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd), ("blue", 0x0000ff), ("blueviolet", 0x8a2be2), ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887), ("cadetblue", 0x5f9ea0), ("chartreuse", 0x7fff00), ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50), ("cornflowerblue", 0x6495ed), ("cornsilk", 0xfff8dc), ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff), ("darkblue", 0x00008b), ("darkcyan", 0x008b8b), ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9), ("darkgreen", 0x006400), ("darkgrey", 0xa9a9a9), ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b), ("darkolivegreen", 0x556b2f), ("darkorange", 0xff8c00), ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000), ("darksalmon", 0xe9967a), ("darkseagreen", 0x8fbc8f), ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f), ("darkslategrey", 0x2f4f4f), ("darkturquoise", 0x00ced1), ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493), ("deepskyblue", 0x00bfff), ("dimgray", 0x696969), ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff), ("firebrick", 0xb22222), ("floralwhite", 0xfffaf0), ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff), ("gainsboro", 0xdcdcdc), ("ghostwhite", 0xf8f8ff), ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520), ("gray", 0x808080), ("green", 0x008000), ("greenyellow", 0xadff2f),
+    ("grey", 0x808080), ("honeydew", 0xf0fff0), ("hotpink", 0xff69b4), ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082), ("ivory", 0xfffff0), ("khaki", 0xf0e68c), ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5), ("lawngreen", 0x7cfc00), ("lemonchiffon", 0xfffacd), ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080), ("lightcyan", 0xe0ffff), ("lightgoldenrodyellow", 0xfafad2), ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90), ("lightgrey", 0xd3d3d3), ("lightpink", 0xffb6c1), ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa), ("lightskyblue", 0x87cefa), ("lightslategray", 0x778899), ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de), ("lightyellow", 0xffffe0), ("lime", 0x00ff00), ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6), ("magenta", 0xff00ff), ("maroon", 0x800000), ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd), ("mediumorchid", 0xba55d3), ("mediumpurple", 0x9370db), ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee), ("mediumspringgreen", 0x00fa9a), ("mediumturquoise", 0x48d1cc), ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970), ("mintcream", 0xf5fffa), ("mistyrose", 0xffe4e1), ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead), ("navy", 0x000080), ("oldlace", 0xfdf5e6), ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23), ("orange", 0xffa500), ("orangered", 0xff4500), ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa), ("palegreen", 0x98fb98), ("paleturquoise", 0xafeeee), ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5), ("peachpuff", 0xffdab9), ("peru", 0xcd853f), ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd), ("powderblue", 0xb0e0e6), ("purple", 0x800080), ("rebeccapurple", 0x663399),
+    ("red", 0xff0000), ("rosybrown", 0xbc8f8f), ("royalblue", 0x4169e1), ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072), ("sandybrown", 0xf4a460), ("seagreen", 0x2e8b57), ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d), ("silver", 0xc0c0c0), ("skyblue", 0x87ceeb), ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090), ("slategrey", 0x708090), ("snow", 0xfffafa), ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4), ("tan", 0xd2b48c), ("teal", 0x008080), ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347), ("turquoise", 0x40e0d0), ("violet", 0xee82ee), ("wheat", 0xf5deb3),
+    ("white", 0xffffff), ("whitesmoke", 0xf5f5f5), ("yellow", 0xffff00), ("yellowgreen", 0x9acd32),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_hex_colors() {
+        assert_eq!(normalize_color("#FFF").as_deref(), Some("#ffffff"));
+        assert_eq!(normalize_color("#1a2B3c").as_deref(), Some("#1a2b3c"));
+        assert_eq!(normalize_color("#f008").as_deref(), Some("#ff000088"));
+        assert_eq!(normalize_color("#11223344").as_deref(), Some("#11223344"));
+        assert_eq!(normalize_color("#12345"), None);
+        assert_eq!(normalize_color("#ggg"), None);
+    }
+
+    #[test]
+    fn normalizes_rgb_colors() {
+        assert_eq!(normalize_color("rgb(255, 0, 128)").as_deref(), Some("#ff0080"));
+        assert_eq!(normalize_color("rgba(0, 0, 0, 0.5)").as_deref(), Some("#00000080"));
+        assert_eq!(normalize_color("rgb(100% 50% 0% / 25%)").as_deref(), Some("#ff800040"));
+        assert_eq!(normalize_color("RGB(300, -5, 0)").as_deref(), Some("#ff0000"));
+        assert_eq!(normalize_color("rgb(1, 2)"), None);
+    }
+
+    #[test]
+    fn normalizes_hsl_colors() {
+        assert_eq!(normalize_color("hsl(0, 100%, 50%)").as_deref(), Some("#ff0000"));
+        assert_eq!(normalize_color("hsl(120deg 100% 25%)").as_deref(), Some("#008000"));
+        assert_eq!(normalize_color("hsl(0.5turn, 100%, 50%)").as_deref(), Some("#00ffff"));
+        assert_eq!(normalize_color("hsla(240, 100%, 50%, 0.5)").as_deref(), Some("#0000ff80"));
+        assert_eq!(normalize_color("hsl(0, 0%, 100%)").as_deref(), Some("#ffffff"));
+    }
+
+    #[test]
+    fn keeps_keywords_and_named_colors() {
+        assert_eq!(normalize_color("Transparent").as_deref(), Some("transparent"));
+        assert_eq!(normalize_color("currentColor").as_deref(), Some(CURRENT_COLOR));
+        assert_eq!(normalize_color(" RebeccaPurple ").as_deref(), Some("#663399"));
+        assert_eq!(normalize_color("notacolor"), None);
+    }
+}
@@ -0,0 +1,129 @@
+use std::io::{Read, Seek};
+use base64::{Engine as _, engine::general_purpose};
+use epub::doc::EpubDoc;
+use scraper::{Html, Selector};
+use crate::types::CoverImage;
+use crate::opf_parser::OpfPackage;
+use crate::nav_parser::{flatten_entries, parse_nav_document};
+use crate::utils::{percent_decode, resolve_relative_path};
+
+/// 표지 이미지 추출
+///
+/// 다음 순서로 찾습니다.
+/// 1. EPUB3 manifest의 `properties="cover-image"`
+/// 2. EPUB2 `<meta name="cover" content="manifest ID">`
+/// 3. `<guide>`의 `type="cover"` 또는 EPUB3 `cover` 랜드마크 (이미지면 그대로, XHTML이면 그 안의 첫 이미지)
+/// 4. ID나 파일명에 `cover`가 들어간 XHTML의 첫 이미지
+pub fn extract_cover<R: Read + Seek>(doc: &mut EpubDoc<R>, package: &OpfPackage) -> Option<CoverImage> {
+    let id = find_cover_id(doc, package)?;
+    let (path, mime_type) = doc.resources.get(&id).cloned()?;
+    let (data, _) = doc.get_resource(&id)?;
+
+    Some(CoverImage {
+        source: format!("data:{};base64,{}", mime_type, general_purpose::STANDARD.encode(&data)),
+        path: percent_decode(&path.display().to_string()),
+        id,
+        mime_type,
+    })
+}
+
+/// 표지 이미지의 manifest ID
+fn find_cover_id<R: Read + Seek>(doc: &mut EpubDoc<R>, package: &OpfPackage) -> Option<String> {
+    let is_image = |doc: &EpubDoc<R>, id: &str| {
+        doc.resources.get(id).is_some_and(|(_, mime_type)| mime_type.starts_with("image/"))
+    };
+
+    // 1. EPUB3 cover-image
+    if let Some(item) = package.manifest.iter().find(|item| {
+        item.properties.as_deref().is_some_and(|properties| properties.split_whitespace().any(|p| p == "cover-image"))
+    }) && is_image(doc, &item.id)
+    {
+        return Some(item.id.clone());
+    }
+
+    // 2. EPUB2 <meta name="cover">: 대부분 manifest ID지만 경로를 적은 책도 있음
+    let opf_path = doc.root_file.display().to_string();
+    if let Some(content) = package.metadata.children_named("meta")
+        .find(|meta| meta.attr("name") == Some("cover"))
+        .and_then(|meta| meta.attr("content"))
+        .map(|content| content.trim().to_string())
+    {
+        if is_image(doc, &content) {
+            return Some(content);
+        }
+        if let Some(id) = resource_id_by_path(doc, &resolve_relative_path(&opf_path, &content))
+            && is_image(doc, &id)
+        {
+            return Some(id);
+        }
+    }
+
+    // 3. guide / landmarks가 가리키는 표지
+    let mut cover_pages: Vec<String> = package.guide.iter()
+        .filter(|reference| reference.reference_type == "cover")
+        .map(|reference| resolve_relative_path(&opf_path, &reference.href))
+        .collect();
+    if let Some(nav_item) = package.nav_item()
+        && let Some((nav_path, _)) = doc.resources.get(&nav_item.id).cloned()
+        && let Some((html, _)) = doc.get_resource_str(&nav_item.id)
+    {
+        let nav_path = nav_path.display().to_string();
+        let landmarks = parse_nav_document(&html, &nav_path, "landmarks").unwrap_or_default();
+        cover_pages.extend(flatten_entries(landmarks).into_iter()
+            .filter(|entry| entry.epub_type.as_deref() == Some("cover"))
+            .filter_map(|entry| entry.href)
+            .map(|href| resolve_relative_path("", &href)));
+    }
+
+    // 4. 이름이 cover인 XHTML
+    cover_pages.extend(package.manifest.iter()
+        .filter(|item| item.media_type == "application/xhtml+xml")
+        .filter(|item| {
+            let file_name = item.href.rsplit('/').next().unwrap_or_default();
+            item.id.to_ascii_lowercase().contains("cover") || file_name.to_ascii_lowercase().contains("cover")
+        })
+        .map(|item| resolve_relative_path(&opf_path, &item.href)));
+
+    for page in cover_pages {
+        let Some(id) = resource_id_by_path(doc, &page) else {
+            continue;
+        };
+        if is_image(doc, &id) {
+            return Some(id);
+        }
+        let Some((html, _)) = doc.get_resource_str(&id) else {
+            continue;
+        };
+        if let Some(src) = first_image_src(&html)
+            && let Some(image_id) = resource_id_by_path(doc, &resolve_relative_path(&page, &src))
+            && is_image(doc, &image_id)
+        {
+            return Some(image_id);
+        }
+    }
+
+    None
+}
+
+/// 아카이브 경로에 해당하는 manifest ID
+fn resource_id_by_path<R: Read + Seek>(doc: &EpubDoc<R>, path: &str) -> Option<String> {
+    doc.resources.iter()
+        .find(|(_, (resource_path, _))| percent_decode(&resource_path.display().to_string()) == percent_decode(path))
+        .map(|(id, _)| id.clone())
+}
+
+/// 문서의 첫 이미지 참조 (`<img src>` 또는 SVG `<image xlink:href>`)
+fn first_image_src(html: &str) -> Option<String> {
+    let document = Html::parse_document(html);
+    let image_selector = Selector::parse("img, image").unwrap();
+
+    document.select(&image_selector).find_map(|element| {
+        let value = element.value();
+        // SVG의 xlink:href는 xlink 네임스페이스의 href 속성으로 파싱됨
+        let src = match value.name() {
+            "img" => value.attr("src"),
+            _ => value.attrs().find(|(name, _)| *name == "href").map(|(_, href)| href),
+        }?;
+        Some(src.trim().to_string()).filter(|src| !src.is_empty())
+    })
+}
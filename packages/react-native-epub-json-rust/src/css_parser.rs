@@ -1,6 +1,17 @@
 use std::collections::HashMap;
 use cssparser::{Parser, ParserInput, Token};
-use crate::types::RnStyles;
+use scraper::ElementRef;
+use scraper::selector::{Parser as SelectorParser, Simple};
+use selectors::NthIndexCache;
+use selectors::matching::{
+    matches_selector, IgnoreNthChildForInvalidation, MatchingContext, MatchingMode, NeedsSelectorFlags, QuirksMode,
+};
+use selectors::parser::{ParseRelative, Selector as ComplexSelector, SelectorList};
+use crate::types::{ConversionOptions, RnDimension, RnStyles};
+use crate::cascade::{reset_inherited_property, resolve_current_color};
+use crate::color::{normalize_color, CURRENT_COLOR};
+use crate::media::{evaluate_condition, media_query_matches};
+use crate::utils::resolve_relative_path;
 
 // WASM 환경에서 console.log 사용을 위한 매크로
 #[cfg(target_arch = "wasm32")]
@@ -17,75 +28,457 @@ macro_rules! log {
     }
 }
 
-/// 전문 CSS 파서로 React Native 스타일 변환
-pub fn parse_css_to_rn_styles(css: &str) -> HashMap<String, RnStyles> {
-    // 기본 EPUB 스타일로 시작
-    let mut styles = create_default_epub_styles();
-    
-    // CSS 규칙 추출
-    let rules = extract_css_rules(css);    
-    
-    let mut parsed_count = 0;
-    let mut failed_count = 0;
-    
-    for (index, (selector, declarations_text)) in rules.iter().enumerate() {
-        match parse_css_declarations_with_cssparser(declarations_text) {
-            Ok(rn_style) => {
-                let style_name = css_selector_to_style_name(selector);
-                
-                styles.insert(style_name, rn_style);
-                parsed_count += 1;
+/// 파싱된 CSS 복합 선택자 (콤마로 구분된 선택자 하나)
+#[derive(Debug, Clone)]
+pub struct CssSelector {
+    text: String,
+    selector: ComplexSelector<Simple>,
+}
+
+impl CssSelector {
+    /// 선택자 문자열 하나를 파싱 (지원하지 않는 선택자면 None)
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut input = ParserInput::new(text);
+        let mut parser = Parser::new(&mut input);
+        let list = SelectorList::parse(&SelectorParser, &mut parser, ParseRelative::No).ok()?;
+        let selector = list.0.into_iter().next()?;
+        Some(CssSelector { text: text.trim().to_string(), selector })
+    }
+
+    /// 원본 선택자 문자열
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// 선택자 명시도 (id, class/속성/의사클래스, 태그 순으로 비교 가능한 값)
+    pub fn specificity(&self) -> u32 {
+        self.selector.specificity()
+    }
+
+    /// 요소가 이 선택자와 매칭되는지 확인
+    pub fn matches(&self, element: &ElementRef) -> bool {
+        let mut nth_index_cache = NthIndexCache::default();
+        let mut context = MatchingContext::new(
+            MatchingMode::Normal,
+            None,
+            &mut nth_index_cache,
+            QuirksMode::NoQuirks,
+            NeedsSelectorFlags::No,
+            IgnoreNthChildForInvalidation::No,
+        );
+        matches_selector(&self.selector, 0, None, element, &mut context)
+    }
+}
+
+/// CSS 선언 하나 (속성 + 값 + !important 여부)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CssDeclaration {
+    pub property: String,
+    pub value: String,
+    pub important: bool,
+}
+
+/// 스타일 규칙의 출처 (캐스케이드 우선순위: UserAgent < Author)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CssOrigin {
+    UserAgent,
+    Author,
+}
+
+/// CSS 스타일 규칙 (선택자 + 선언 목록)
+#[derive(Debug, Clone)]
+pub struct CssRule {
+    pub selector: CssSelector,
+    pub declarations: Vec<CssDeclaration>,
+    pub origin: CssOrigin,
+}
+
+impl CssRule {
+    /// 규칙의 선언을 RN 스타일로 변환 (!important 선언이 우선)
+    pub fn style(&self) -> RnStyles {
+        let mut style = RnStyles::default();
+        let context = LengthContext::default();
+        apply_css_declarations(&mut style, self.declarations.iter().filter(|d| !d.important), &context);
+        apply_css_declarations(&mut style, self.declarations.iter().filter(|d| d.important), &context);
+        style
+    }
+}
+
+/// 기본 EPUB 스타일 (user agent 스타일시트)
+const USER_AGENT_CSS: &str = r#"
+p { margin-top: 8px; margin-bottom: 8px; }
+h1, h2, h3, h4, h5, h6 { font-weight: bold; }
+h1 { font-size: 24px; }
+h2 { font-size: 20px; }
+h3 { font-size: 18px; }
+h4 { font-size: 16px; }
+h5 { font-size: 14px; }
+h6 { font-size: 12px; }
+strong, b { font-weight: bold; }
+em, i, cite { font-style: italic; }
+u { text-decoration: underline; }
+blockquote { margin-top: 8px; margin-bottom: 8px; margin-left: 16px; margin-right: 16px; font-style: italic; }
+code, tt { font-family: monospace; font-size: 14px; }
+pre { font-family: monospace; font-size: 14px; margin-top: 8px; margin-bottom: 8px; white-space: pre; }
+sup, sub, small { font-size: 12px; }
+big { font-size: 20px; }
+center { text-align: center; }
+ul, ol { margin-top: 8px; margin-bottom: 8px; }
+li ul, li ol { margin-top: 0px; margin-bottom: 0px; }
+ul { list-style-type: disc; }
+ol { list-style-type: decimal; }
+ul ul, ol ul { list-style-type: circle; }
+ul ul ul, ul ol ul, ol ul ul, ol ol ul { list-style-type: square; }
+table { margin-top: 8px; margin-bottom: 8px; }
+th { font-weight: bold; text-align: center; }
+td, th { padding-top: 2px; padding-bottom: 2px; padding-left: 4px; padding-right: 4px; }
+caption { text-align: center; }
+a[href] { text-decoration: underline; }
+"#;
+
+/// 선택자 매칭이 가능한 형태로 파싱된 스타일시트
+///
+/// 규칙은 소스 순서대로 저장되며, 여러 스타일시트를 `extend`로 이어 붙이면
+/// 이어 붙인 순서가 곧 캐스케이드의 소스 순서가 됩니다.
+#[derive(Debug, Clone, Default)]
+pub struct Stylesheet {
+    pub rules: Vec<CssRule>,
+    /// 대상 환경에 적용되는 `@font-face` 선언들
+    pub font_faces: Vec<FontFace>,
+}
+
+/// `@font-face` 선언
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontFace {
+    /// CSS에서 사용하는 폰트 패밀리 이름
+    pub family: String,
+    /// `src`의 `url()` 목록 (스타일시트 위치 기준으로 해석된 아카이브 경로, 또는 data URI)
+    pub sources: Vec<String>,
+    /// `font-weight` 값 (`normal`, `bold`, `100`~`900`)
+    pub weight: String,
+    /// `font-style` 값 (`normal`, `italic`, `oblique`)
+    pub style: String,
+}
+
+impl Stylesheet {
+    /// CSS 문자열을 저자(author) 스타일시트로 파싱 (기본 변환 옵션, `@import` 무시)
+    pub fn parse(css: &str) -> Self {
+        Self::parse_with(css, "", &ConversionOptions::default(), &mut |_| None)
+    }
+
+    /// 변환 옵션의 대상 환경으로 `@media`를 평가하며 저자 스타일시트로 파싱
+    ///
+    /// `path`는 스타일시트의 아카이브 경로로, `@font-face`의 상대 URL 해석에 사용됩니다.
+    /// `import`는 `@import`된 URL(작성된 그대로)을 받아 파싱된 스타일시트를 돌려주며,
+    /// 가져온 규칙은 `@import` 위치에 끼워 넣어집니다.
+    pub fn parse_with(
+        css: &str,
+        path: &str,
+        options: &ConversionOptions,
+        import: &mut dyn FnMut(&str) -> Option<Stylesheet>,
+    ) -> Self {
+        let mut stylesheet = Stylesheet::default();
+        parse_rules(css, path, CssOrigin::Author, options, import, &mut stylesheet);
+        stylesheet
+    }
+
+    /// 기본 EPUB 스타일을 담은 user agent 스타일시트
+    pub fn user_agent() -> Self {
+        let mut stylesheet = Stylesheet::default();
+        parse_rules(USER_AGENT_CSS, "", CssOrigin::UserAgent, &ConversionOptions::default(), &mut |_| None, &mut stylesheet);
+        stylesheet
+    }
+
+    /// 다른 스타일시트의 규칙을 뒤에 이어 붙임
+    pub fn extend(&mut self, other: Stylesheet) {
+        self.rules.extend(other.rules);
+        self.font_faces.extend(other.font_faces);
+    }
+
+    /// 요소와 매칭되는 규칙들을 (소스 순서, 규칙) 쌍으로 반환
+    pub fn matching_rules<'a>(&'a self, element: &'a ElementRef) -> impl Iterator<Item = (usize, &'a CssRule)> + 'a {
+        self.rules.iter().enumerate().filter(move |(_, rule)| rule.selector.matches(element))
+    }
+
+    /// 선택자 문자열 → 스타일 맵 (JSON 출력용)
+    pub fn to_style_map(&self) -> HashMap<String, RnStyles> {
+        let mut styles: HashMap<String, RnStyles> = HashMap::new();
+        for rule in &self.rules {
+            let key = rule.selector.text().to_string();
+            let mut style = rule.style();
+            let color = style.color.clone();
+            resolve_current_color(&mut style, color.as_deref());
+            let merged = merge_styles(styles.remove(&key), Some(style));
+            if let Some(style) = merged {
+                styles.insert(key, style);
             }
-            Err(e) => {
-                failed_count += 1;
+        }
+        styles
+    }
+}
+
+/// CSS 규칙들을 파싱해 `stylesheet`에 추가 (at-rule은 대상 환경에 맞는 것만 펼침)
+fn parse_rules(
+    css: &str,
+    path: &str,
+    origin: CssOrigin,
+    options: &ConversionOptions,
+    import: &mut dyn FnMut(&str) -> Option<Stylesheet>,
+    stylesheet: &mut Stylesheet,
+) {
+    for raw_rule in extract_css_rules(css) {
+        match raw_rule {
+            RawCssRule::Statement(statement) => {
+                if let Some((url, media)) = parse_import_statement(&statement) {
+                    if !media_query_matches(&media, options) {
+                        continue;
+                    }
+                    match import(&url) {
+                        Some(imported) => stylesheet.extend(imported),
+                        None => {
+                            log!("   ⚠️  Failed to import stylesheet: {}", url);
+                        }
+                    }
+                }
+                // @charset, @namespace 등은 무시
+            }
+            RawCssRule::Block(prelude, body) => {
+                if let Some(at_rule) = prelude.strip_prefix('@') {
+                    let (name, condition) = at_rule.split_once(|c: char| c.is_whitespace() || c == '(')
+                        .map(|(name, _)| (name, &at_rule[name.len()..]))
+                        .unwrap_or((at_rule, ""));
+                    match name.to_ascii_lowercase().as_str() {
+                        "media" => {
+                            if media_query_matches(condition, options) {
+                                parse_rules(&body, path, origin, options, import, stylesheet);
+                            }
+                        }
+                        "supports" => {
+                            if supports_condition_matches(condition) {
+                                parse_rules(&body, path, origin, options, import, stylesheet);
+                            }
+                        }
+                        "font-face" => {
+                            if let Some(font_face) = parse_font_face(&body, path) {
+                                stylesheet.font_faces.push(font_face);
+                            }
+                        }
+                        _ => {
+                            log!("   ⚠️  Unsupported at-rule: @{}", name);
+                        }
+                    }
+                    continue;
+                }
+
+                let declarations = parse_css_declarations(&body);
+                if declarations.is_empty() {
+                    continue;
+                }
+
+                // 콤마로 구분된 선택자는 각각 별도 규칙으로 (하나가 실패해도 나머지는 유지)
+                for part in split_top_level(&prelude, ',') {
+                    match CssSelector::parse(&part) {
+                        Some(selector) => stylesheet.rules.push(CssRule { selector, declarations: declarations.clone(), origin }),
+                        None => {
+                            log!("   ⚠️  Unsupported selector: {}", part);
+                        }
+                    }
+                }
             }
         }
     }
-    
-    let success_rate = if rules.len() > 0 {
-        (parsed_count as f32 / rules.len() as f32 * 100.0).round()
+}
+
+/// `@font-face` 블록 파싱 (`src`의 `url()`은 스타일시트 경로 기준으로 해석)
+fn parse_font_face(body: &str, path: &str) -> Option<FontFace> {
+    let mut family = None;
+    let mut sources = Vec::new();
+    let mut weight = "normal".to_string();
+    let mut style = "normal".to_string();
+
+    for declaration in parse_css_declarations(body) {
+        match declaration.property.as_str() {
+            "font-family" => family = parse_font_family(&declaration.value),
+            "font-weight" => weight = declaration.value.to_ascii_lowercase(),
+            "font-style" => style = declaration.value.to_ascii_lowercase(),
+            "src" => {
+                sources = split_top_level(&declaration.value, ',').into_iter()
+                    .filter_map(|source| {
+                        let url = source.trim().strip_prefix("url(")?;
+                        let url = url[..url.find(')')?].trim().trim_matches(|c| c == '"' || c == '\'');
+                        Some(if url.starts_with("data:") {
+                            url.to_string()
+                        } else {
+                            resolve_relative_path(path, url)
+                        })
+                    })
+                    .collect();
+            }
+            _ => {}
+        }
+    }
+
+    if sources.is_empty() {
+        return None;
+    }
+    Some(FontFace { family: family?, sources, weight, style })
+}
+
+/// CSS에서 최상위 `@import` URL 목록 추출 (작성된 그대로)
+pub fn css_import_urls(css: &str) -> Vec<String> {
+    extract_css_rules(css).into_iter()
+        .filter_map(|raw_rule| match raw_rule {
+            RawCssRule::Statement(statement) => parse_import_statement(&statement).map(|(url, _)| url),
+            RawCssRule::Block(..) => None,
+        })
+        .collect()
+}
+
+/// `@import url("a.css") screen;` → (URL, 미디어 쿼리)
+fn parse_import_statement(statement: &str) -> Option<(String, String)> {
+    // at-rule 이름과 url() 함수 이름은 대소문자를 구분하지 않음
+    let rest = statement.get(..7)
+        .filter(|keyword| keyword.eq_ignore_ascii_case("@import"))
+        .map(|_| statement[7..].trim_start())?;
+
+    let (url, media) = if rest.get(..4).is_some_and(|function| function.eq_ignore_ascii_case("url(")) {
+        let rest = &rest[4..];
+        let end = rest.find(')')?;
+        (&rest[..end], &rest[end + 1..])
     } else {
-        100.0
+        let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let end = rest[1..].find(quote)? + 1;
+        (&rest[..=end], &rest[end + 1..])
     };
-    
-    styles
+
+    let url = url.trim().trim_matches(|c| c == '"' || c == '\'');
+    if url.is_empty() {
+        return None;
+    }
+    Some((url.to_string(), media.trim().to_string()))
 }
 
-/// CSS 규칙을 추출 (선택자 + 선언부)
-fn extract_css_rules(css: &str) -> Vec<(String, String)> {
+/// `@supports` 조건 평가: 변환 가능한 속성/값이면 참
+fn supports_condition_matches(condition: &str) -> bool {
+    evaluate_condition(condition, &|leaf| {
+        // selector(...) 함수는 선택자 파싱 가능 여부로 판단
+        if let Some(selector) = leaf.strip_prefix("selector(").and_then(|rest| rest.strip_suffix(')')) {
+            return CssSelector::parse(selector).is_some();
+        }
+        let Some((property, value)) = leaf.split_once(':') else {
+            return false;
+        };
+        let mut style = RnStyles::default();
+        apply_css_property_to_rn_style(
+            &mut style,
+            &property.trim().to_ascii_lowercase(),
+            value.trim(),
+            &LengthContext::default(),
+        );
+        style != RnStyles::default()
+    })
+}
+
+/// 전문 CSS 파서로 React Native 스타일 변환 (선택자 문자열 → 스타일 맵)
+pub fn parse_css_to_rn_styles(css: &str) -> HashMap<String, RnStyles> {
+    // 기본 EPUB 스타일로 시작
+    let mut stylesheet = Stylesheet::user_agent();
+    stylesheet.extend(Stylesheet::parse(css));
+    stylesheet.to_style_map()
+}
+
+/// 최상위 구분자 기준으로 분할 (괄호/대괄호/따옴표 내부의 구분자는 무시)
+///
+/// 선택자 목록의 콤마, 선언 블록의 세미콜론, `src` 목록의 콤마에 사용합니다.
+/// `url(data:font/woff2;base64,...)`처럼 값 안에 구분자가 있어도 나뉘지 않습니다.
+fn split_top_level(text: &str, separator: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+
+    for ch in text.chars() {
+        match (quote, ch) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, '(' | '[') => depth += 1,
+            (None, ')' | ']') => depth -= 1,
+            (None, c) if c == separator && depth == 0 => {
+                parts.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        current.push(ch);
+    }
+    parts.push(current);
+
+    parts.into_iter()
+        .map(|part| part.trim().to_string())
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+/// CSS 주석 제거
+fn strip_css_comments(css: &str) -> String {
+    let mut result = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        result.push_str(&rest[..start]);
+        match rest[start + 2..].find("*/") {
+            Some(end) => rest = &rest[start + 2 + end + 2..],
+            None => rest = "",
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 최상위 CSS 구문 단위
+enum RawCssRule {
+    /// 세미콜론으로 끝나는 at-rule (`@import ...`, `@charset ...`)
+    Statement(String),
+    /// 블록 규칙 (선택자 또는 at-rule 머리, 블록 내용)
+    Block(String, String),
+}
+
+/// CSS 규칙을 추출 (선택자 + 선언부, at-rule 문장)
+fn extract_css_rules(css: &str) -> Vec<RawCssRule> {
+    let css = strip_css_comments(css);
     let mut rules = Vec::new();
     let mut current_rule = String::new();
     let mut brace_count = 0;
-    let mut in_rule = false;
+    let mut quote: Option<char> = None;
     
     for ch in css.chars() {
-        match ch {
-            '{' => {
-                brace_count += 1;
-                if brace_count == 1 {
-                    in_rule = true;
-                }
-                current_rule.push(ch);
-            }
-            '}' => {
+        current_rule.push(ch);
+        match (quote, ch) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, '{') => brace_count += 1,
+            (None, '}') if brace_count > 0 => {
                 brace_count -= 1;
-                current_rule.push(ch);
-                if brace_count == 0 && in_rule {
+                if brace_count == 0 {
                     if let Some(pos) = current_rule.find('{') {
                         let selector = current_rule[..pos].trim().to_string();
                         let declarations = current_rule[pos + 1..current_rule.len() - 1].trim().to_string();
                         if !selector.is_empty() && !declarations.is_empty() {
-                            rules.push((selector, declarations));
-                        } else {
+                            rules.push(RawCssRule::Block(selector, declarations));
                         }
                     }
                     current_rule.clear();
-                    in_rule = false;
                 }
             }
-            _ => {
-                current_rule.push(ch);
+            (None, ';') if brace_count == 0 => {
+                let statement = current_rule[..current_rule.len() - 1].trim();
+                if statement.starts_with('@') {
+                    rules.push(RawCssRule::Statement(statement.to_string()));
+                }
+                current_rule.clear();
             }
+            _ => {}
         }
     }
     
@@ -93,97 +486,56 @@ fn extract_css_rules(css: &str) -> Vec<(String, String)> {
 }
 
 /// cssparser를 사용한 전문 CSS 선언 파싱 (공개 함수)
-pub fn parse_css_declarations_with_cssparser(declarations: &str) -> Result<RnStyles, String> {
-    let mut style = RnStyles {
-        // 텍스트 스타일
-        fontSize: None,
-        fontWeight: None,
-        fontFamily: None,
-        fontStyle: None,
-        color: None,
-        textAlign: None,
-        textDecorationLine: None,
-        textTransform: None,
-        lineHeight: None,
-        textIndent: None,
-        
-        // 배경 및 색상
-        backgroundColor: None,
-        opacity: None,
-        
-        // 여백
-        marginTop: None,
-        marginBottom: None,
-        marginLeft: None,
-        marginRight: None,
-        paddingTop: None,
-        paddingBottom: None,
-        paddingLeft: None,
-        paddingRight: None,
-        
-        // 크기 및 레이아웃
-        width: None,
-        height: None,
-        minWidth: None,
-        maxWidth: None,
-        minHeight: None,
-        maxHeight: None,
-        
-        // 포지셔닝
-        position: None,
-        top: None,
-        bottom: None,
-        left: None,
-        right: None,
-        zIndex: None,
-        
-        // Flexbox
-        display: None,
-        flexDirection: None,
-        justifyContent: None,
-        alignItems: None,
-        alignSelf: None,
-        flexWrap: None,
-        flex: None,
-        flexGrow: None,
-        flexShrink: None,
-        flexBasis: None,
-        
-        // 테두리
-        borderWidth: None,
-        borderTopWidth: None,
-        borderBottomWidth: None,
-        borderLeftWidth: None,
-        borderRightWidth: None,
-        borderColor: None,
-        borderTopColor: None,
-        borderBottomColor: None,
-        borderLeftColor: None,
-        borderRightColor: None,
-        borderRadius: None,
-        borderStyle: None,
+pub fn parse_css_declarations_with_cssparser(declarations: &str) -> RnStyles {
+    let declarations = parse_css_declarations(declarations);
+    let mut style = RnStyles::default();
+    let context = LengthContext::default();
+    apply_css_declarations(&mut style, declarations.iter().filter(|d| !d.important), &context);
+    apply_css_declarations(&mut style, declarations.iter().filter(|d| d.important), &context);
+    let color = style.color.clone();
+    resolve_current_color(&mut style, color.as_deref());
+    style
+}
+
+/// 선언 블록을 개별 선언 목록으로 파싱 (`!important` 분리)
+pub fn parse_css_declarations(declarations: &str) -> Vec<CssDeclaration> {
+    // 각 선언을 최상위 세미콜론으로 분할해서 개별 파싱 (url()과 문자열 안의 세미콜론 제외)
+    split_top_level(declarations, ';').into_iter().filter_map(|declaration| {
+        let (declaration, important) = split_important(&declaration);
         
-        // 오버플로우
-        overflow: None,
-    };
-    
-    let mut property_count = 0;
-    
-    // 각 선언을 세미콜론으로 분할해서 개별 파싱
-    for declaration in declarations.split(';') {
-        let declaration = declaration.trim();
-        if declaration.is_empty() {
+        // cssparser로 속성과 값 추출
+        parse_css_declaration_simple(declaration).map(|(property, value)| CssDeclaration {
+            property: property.to_ascii_lowercase(),
+            value,
+            important,
+        })
+    }).collect()
+}
+
+/// 선언 목록을 순서대로 RN 스타일에 적용 (뒤의 선언이 앞의 선언을 덮어씀)
+pub fn apply_css_declarations<'a>(
+    style: &mut RnStyles,
+    declarations: impl Iterator<Item = &'a CssDeclaration>,
+    context: &LengthContext,
+) {
+    for declaration in declarations {
+        if declaration.value.eq_ignore_ascii_case("inherit") || declaration.value.eq_ignore_ascii_case("unset") {
+            reset_inherited_property(style, &declaration.property);
             continue;
         }
-        
-        // cssparser로 속성과 값 추출
-        if let Some((property, value)) = parse_css_declaration_simple(declaration) {
-            apply_css_property_to_rn_style(&mut style, &property, &value);
-            property_count += 1;
+        apply_css_property_to_rn_style(style, &declaration.property, &declaration.value, context);
+    }
+}
+
+/// 선언 끝의 `!important` 분리
+fn split_important(declaration: &str) -> (&str, bool) {
+    if let Some(pos) = declaration.rfind('!') {
+        let flag = declaration[pos + 1..].trim();
+        if flag.eq_ignore_ascii_case("important") {
+            return (declaration[..pos].trim_end(), true);
         }
     }
-    
-    Ok(style)
+    (declaration, false)
 }
 
 /// 간단한 CSS 선언 파싱 (cssparser 기반)
@@ -203,28 +555,7 @@ fn parse_css_declaration_simple(declaration: &str) -> Option<(String, String)> {
     }
     
     // 값 파싱 - 여러 토큰을 수집
-    let mut value_parts = Vec::new();
-    while !parser.is_exhausted() {
-        match parser.next() {
-            Ok(token) => {
-                let value_part = match token {
-                    Token::Ident(s) => s.to_string(),
-                    Token::QuotedString(s) => format!("\"{}\"", s),
-                    Token::Number { value, .. } => value.to_string(),
-                    Token::Percentage { unit_value, .. } => format!("{}%", unit_value * 100.0),
-                    Token::Dimension { value, unit, .. } => format!("{}{}", value, unit),
-                    Token::Hash(s) => format!("#{}", s),
-                    Token::Function(name) => {
-                        // 함수는 간단히 처리
-                        format!("{}(...)", name)
-                    }
-                    _ => continue,
-                };
-                value_parts.push(value_part);
-            }
-            Err(_) => break,
-        }
-    }
+    let value_parts = serialize_value_tokens(&mut parser);
     
     if value_parts.is_empty() {
         return None;
@@ -234,57 +565,104 @@ fn parse_css_declaration_simple(declaration: &str) -> Option<(String, String)> {
     Some((property, value))
 }
 
+/// 값 토큰들을 문자열 조각으로 직렬화 (함수는 인자까지 포함: `rgb(255, 0, 0)`)
+fn serialize_value_tokens(parser: &mut Parser) -> Vec<String> {
+    let mut value_parts = Vec::new();
+    while !parser.is_exhausted() {
+        let token = match parser.next() {
+            Ok(token) => token.clone(),
+            Err(_) => break,
+        };
+        let value_part = match token {
+            Token::Ident(s) => s.to_string(),
+            Token::QuotedString(s) => format!("\"{}\"", s),
+            Token::Number { value, .. } => value.to_string(),
+            Token::Percentage { unit_value, .. } => format!("{}%", unit_value * 100.0),
+            Token::Dimension { value, unit, .. } => format!("{}{}", value, unit),
+            Token::Hash(s) | Token::IDHash(s) => format!("#{}", s),
+            Token::Delim('/') => "/".to_string(),
+            Token::Comma => ",".to_string(),
+            Token::UnquotedUrl(url) => format!("url({})", url),
+            Token::Function(name) => {
+                let arguments = parser
+                    .parse_nested_block(|nested| Ok::<_, cssparser::ParseError<()>>(serialize_value_tokens(nested)))
+                    .unwrap_or_default();
+                format!("{}({})", name, arguments.join(" ").replace(" ,", ","))
+            }
+            _ => continue,
+        };
+        value_parts.push(value_part);
+    }
+    value_parts
+}
+
 /// CSS 속성을 RN 스타일에 적용
-fn apply_css_property_to_rn_style(style: &mut RnStyles, property: &str, value: &str) {
+fn apply_css_property_to_rn_style(style: &mut RnStyles, property: &str, value: &str, context: &LengthContext) {
     match property {
         // 텍스트 스타일
-        "font-size" => style.fontSize = parse_size_value(value),
+        "font-size" => style.fontSize = parse_font_size(value, context),
         "font-weight" => style.fontWeight = Some(value.to_string()),
-        "font-family" => style.fontFamily = Some(value.trim_matches('"').to_string()),
+        "font-family" => style.fontFamily = parse_font_family(value),
         "font-style" => style.fontStyle = Some(value.to_string()),
-        "color" => style.color = Some(value.to_string()),
+        "color" => match normalize_color(value).as_deref() {
+            // color의 currentColor는 부모 색상 상속과 같음
+            Some(CURRENT_COLOR) => reset_inherited_property(style, property),
+            Some(color) => style.color = Some(color.to_string()),
+            None => {}
+        },
         "text-align" => style.textAlign = Some(value.to_string()),
         "text-decoration" | "text-decoration-line" => {
-            if value.contains("underline") {
-                style.textDecorationLine = Some("underline".to_string());
-            } else if value.contains("line-through") {
-                style.textDecorationLine = Some("line-through".to_string());
-            } else if value.contains("none") {
-                style.textDecorationLine = Some("none".to_string());
+            // RN은 underline, line-through와 둘의 조합만 지원 (overline, 색상, 선 스타일은 무시)
+            let has = |keyword: &str| value.split_whitespace().any(|part| part.eq_ignore_ascii_case(keyword));
+            let line = match (has("underline"), has("line-through")) {
+                (true, true) => Some("underline line-through"),
+                (true, false) => Some("underline"),
+                (false, true) => Some("line-through"),
+                (false, false) => Some("none").filter(|_| has("none")),
+            };
+            if let Some(line) = line {
+                style.textDecorationLine = Some(line.to_string());
             }
         }
         "text-transform" => style.textTransform = Some(value.to_string()),
-        "line-height" => style.lineHeight = parse_size_value(value),
-        "text-indent" => style.textIndent = parse_size_value(value),
+        "line-height" => {
+            let (line_height, multiplier) = parse_line_height(value, context);
+            style.lineHeight = line_height;
+            style.lineHeightMultiplier = multiplier;
+        }
+        "text-indent" => style.textIndent = resolve_length(value, context),
+        "list-style-type" => style.listStyleType = Some(value.to_string()),
+        "list-style" => apply_list_style_shorthand(style, value),
+        "white-space" => style.whiteSpace = Some(value.to_ascii_lowercase()),
         
         // 배경 및 색상
-        "background-color" => style.backgroundColor = Some(value.to_string()),
+        "background-color" => apply_color(&mut style.backgroundColor, value),
         "opacity" => style.opacity = value.parse().ok(),
         
         // 여백
-        "margin-top" => style.marginTop = parse_size_value(value),
-        "margin-bottom" => style.marginBottom = parse_size_value(value),
-        "margin-left" => style.marginLeft = parse_size_value(value),
-        "margin-right" => style.marginRight = parse_size_value(value),
-        "padding-top" => style.paddingTop = parse_size_value(value),
-        "padding-bottom" => style.paddingBottom = parse_size_value(value),
-        "padding-left" => style.paddingLeft = parse_size_value(value),
-        "padding-right" => style.paddingRight = parse_size_value(value),
+        "margin-top" => style.marginTop = parse_dimension(value, context),
+        "margin-bottom" => style.marginBottom = parse_dimension(value, context),
+        "margin-left" => style.marginLeft = parse_dimension(value, context),
+        "margin-right" => style.marginRight = parse_dimension(value, context),
+        "padding-top" => style.paddingTop = parse_dimension(value, context),
+        "padding-bottom" => style.paddingBottom = parse_dimension(value, context),
+        "padding-left" => style.paddingLeft = parse_dimension(value, context),
+        "padding-right" => style.paddingRight = parse_dimension(value, context),
         
         // 크기 및 레이아웃
-        "width" => style.width = parse_size_value(value),
-        "height" => style.height = parse_size_value(value),
-        "min-width" => style.minWidth = parse_size_value(value),
-        "max-width" => style.maxWidth = parse_size_value(value),
-        "min-height" => style.minHeight = parse_size_value(value),
-        "max-height" => style.maxHeight = parse_size_value(value),
+        "width" => style.width = parse_dimension(value, context),
+        "height" => style.height = parse_dimension(value, context),
+        "min-width" => style.minWidth = parse_dimension(value, context),
+        "max-width" => style.maxWidth = parse_dimension(value, context),
+        "min-height" => style.minHeight = parse_dimension(value, context),
+        "max-height" => style.maxHeight = parse_dimension(value, context),
         
         // 포지셔닝
         "position" => style.position = Some(value.to_string()),
-        "top" => style.top = parse_size_value(value),
-        "bottom" => style.bottom = parse_size_value(value),
-        "left" => style.left = parse_size_value(value),
-        "right" => style.right = parse_size_value(value),
+        "top" => style.top = parse_dimension(value, context),
+        "bottom" => style.bottom = parse_dimension(value, context),
+        "left" => style.left = parse_dimension(value, context),
+        "right" => style.right = parse_dimension(value, context),
         "z-index" => style.zIndex = value.parse().ok(),
         
         // Flexbox
@@ -297,127 +675,409 @@ fn apply_css_property_to_rn_style(style: &mut RnStyles, property: &str, value: &
         "flex" => style.flex = value.parse().ok(),
         "flex-grow" => style.flexGrow = value.parse().ok(),
         "flex-shrink" => style.flexShrink = value.parse().ok(),
-        "flex-basis" => style.flexBasis = parse_size_value(value),
+        "flex-basis" => style.flexBasis = parse_dimension(value, context),
         
         // 테두리
-        "border-width" => style.borderWidth = parse_size_value(value),
-        "border-top-width" => style.borderTopWidth = parse_size_value(value),
-        "border-bottom-width" => style.borderBottomWidth = parse_size_value(value),
-        "border-left-width" => style.borderLeftWidth = parse_size_value(value),
-        "border-right-width" => style.borderRightWidth = parse_size_value(value),
-        "border-color" => style.borderColor = Some(value.to_string()),
-        "border-top-color" => style.borderTopColor = Some(value.to_string()),
-        "border-bottom-color" => style.borderBottomColor = Some(value.to_string()),
-        "border-left-color" => style.borderLeftColor = Some(value.to_string()),
-        "border-right-color" => style.borderRightColor = Some(value.to_string()),
-        "border-radius" => style.borderRadius = parse_size_value(value),
-        "border-style" => style.borderStyle = Some(value.to_string()),
+        "border-top-width" => style.borderTopWidth = parse_border_width(value, context),
+        "border-bottom-width" => style.borderBottomWidth = parse_border_width(value, context),
+        "border-left-width" => style.borderLeftWidth = parse_border_width(value, context),
+        "border-right-width" => style.borderRightWidth = parse_border_width(value, context),
+        "border-top-color" => apply_color(&mut style.borderTopColor, value),
+        "border-bottom-color" => apply_color(&mut style.borderBottomColor, value),
+        "border-left-color" => apply_color(&mut style.borderLeftColor, value),
+        "border-right-color" => apply_color(&mut style.borderRightColor, value),
+        // 모서리별 반경은 지원하지 않으므로 첫 번째 값만 사용
+        "border-radius" => {
+            style.borderRadius = split_css_value(value).first().and_then(|radius| resolve_length(radius, context));
+        }
+        // RN은 변마다 다른 테두리 스타일을 지원하지 않으므로 첫 번째 값만 사용
+        "border-style" => style.borderStyle = split_css_value(value).first().map(|s| s.to_string()),
         
         // 오버플로우
         "overflow" => style.overflow = Some(value.to_string()),
         
+        // 단축 속성
+        "margin" | "padding" | "border-width" | "border-color" => apply_box_shorthand(style, property, value, context),
+        "border" | "border-top" | "border-right" | "border-bottom" | "border-left" => {
+            apply_border_shorthand(style, property, value, context);
+        }
+        "font" => apply_font_shorthand(style, value, context),
+        "background" => apply_background_shorthand(style, value, context),
+        
         _ => {} // 지원하지 않는 속성
     }
 }
 
-/// CSS 크기 값을 픽셀 단위로 변환
-pub fn parse_size_value(value: &str) -> Option<f32> {
-    if value.ends_with("px") {
-        value.trim_end_matches("px").parse().ok()
-    } else if value.ends_with("em") {
-        value.trim_end_matches("em").parse::<f32>().ok().map(|v| v * 16.0)
-    } else if value.ends_with("pt") {
-        value.trim_end_matches("pt").parse::<f32>().ok().map(|v| v * 1.33)
-    } else {
-        value.parse().ok()
+const BOX_SIDES: [&str; 4] = ["top", "right", "bottom", "left"];
+
+const BORDER_STYLE_KEYWORDS: [&str; 10] = [
+    "none", "hidden", "dotted", "dashed", "solid", "double", "groove", "ridge", "inset", "outset",
+];
+
+/// CSS 값을 공백 기준으로 분할 (따옴표/괄호 내부 공백은 유지)
+fn split_css_value(value: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    let mut start: Option<usize> = None;
+
+    for (index, ch) in value.char_indices() {
+        match (quote, ch) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, c) if c.is_whitespace() && depth == 0 => {
+                if let Some(begin) = start.take() {
+                    parts.push(&value[begin..index]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        start.get_or_insert(index);
     }
+    if let Some(begin) = start {
+        parts.push(&value[begin..]);
+    }
+
+    parts
 }
 
-/// CSS 선택자를 스타일 이름으로 변환
-pub fn css_selector_to_style_name(selector: &str) -> String {
-    selector.trim().replace(".", "").replace("#", "").replace(" ", "_")
+/// 1~4개 값의 박스 단축 속성 (`margin`, `padding`, `border-width`, `border-color`) 전개
+fn apply_box_shorthand(style: &mut RnStyles, property: &str, value: &str, context: &LengthContext) {
+    let parts = split_css_value(value);
+    let [top, right, bottom, left] = match parts.as_slice() {
+        [all] => [*all; 4],
+        [vertical, horizontal] => [*vertical, *horizontal, *vertical, *horizontal],
+        [top, horizontal, bottom] => [*top, *horizontal, *bottom, *horizontal],
+        [top, right, bottom, left] => [*top, *right, *bottom, *left],
+        _ => return,
+    };
+
+    for (side, side_value) in BOX_SIDES.iter().zip([top, right, bottom, left]) {
+        let longhand = match property {
+            "border-width" => format!("border-{}-width", side),
+            "border-color" => format!("border-{}-color", side),
+            _ => format!("{}-{}", property, side),
+        };
+        apply_css_property_to_rn_style(style, &longhand, side_value, context);
+    }
+
+    // 네 변이 모두 같으면 RN의 공통 속성 하나로 합침
+    if property == "border-width" && top == right && right == bottom && bottom == left {
+        style.borderWidth = style.borderTopWidth.take();
+        style.borderRightWidth = None;
+        style.borderBottomWidth = None;
+        style.borderLeftWidth = None;
+    } else if property == "border-color" && top == right && right == bottom && bottom == left {
+        style.borderColor = style.borderTopColor.take();
+        style.borderRightColor = None;
+        style.borderBottomColor = None;
+        style.borderLeftColor = None;
+    }
 }
 
-/// 기본 EPUB 스타일 생성
-pub fn create_default_epub_styles() -> HashMap<String, RnStyles> {
-    let mut styles = HashMap::new();
-    
-    // 기본 body 스타일
-    styles.insert("body".to_string(), RnStyles {
-        fontSize: Some(16.0),
-        fontFamily: Some("serif".to_string()),
-        color: Some("#000000".to_string()),
-        lineHeight: Some(1.5),
-        paddingLeft: Some(16.0),
-        paddingRight: Some(16.0),
-        paddingTop: Some(16.0),
-        paddingBottom: Some(16.0),
-        ..Default::default()
-    });
-    
-    // h1-h6 헤딩 스타일
-    styles.insert("h1".to_string(), RnStyles {
-        fontSize: Some(28.0),
-        fontWeight: Some("bold".to_string()),
-        marginTop: Some(24.0),
-        marginBottom: Some(16.0),
-        ..Default::default()
-    });
-    
-    styles.insert("h2".to_string(), RnStyles {
-        fontSize: Some(24.0),
-        fontWeight: Some("bold".to_string()),
-        marginTop: Some(20.0),
-        marginBottom: Some(14.0),
-        ..Default::default()
-    });
-    
-    styles.insert("h3".to_string(), RnStyles {
-        fontSize: Some(20.0),
-        fontWeight: Some("bold".to_string()),
-        marginTop: Some(16.0),
-        marginBottom: Some(12.0),
-        ..Default::default()
-    });
-    
-    styles.insert("p".to_string(), RnStyles {
-        fontSize: Some(16.0),
-        marginBottom: Some(12.0),
-        textAlign: Some("justify".to_string()),
-        ..Default::default()
-    });
-    
-    // 강조 스타일
-    styles.insert("em".to_string(), RnStyles {
-        fontStyle: Some("italic".to_string()),
-        ..Default::default()
-    });
-    
-    styles.insert("strong".to_string(), RnStyles {
-        fontWeight: Some("bold".to_string()),
-        ..Default::default()
-    });
-    
-    // 인용 스타일
-    styles.insert("blockquote".to_string(), RnStyles {
-        marginLeft: Some(24.0),
-        marginRight: Some(24.0),
-        marginTop: Some(16.0),
-        marginBottom: Some(16.0),
-        paddingLeft: Some(16.0),
-        borderLeftWidth: Some(3.0),
-        borderLeftColor: Some("#cccccc".to_string()),
-        fontStyle: Some("italic".to_string()),
-        ..Default::default()
-    });
-    
-    // 중앙 정렬
-    styles.insert("center".to_string(), RnStyles {
-        textAlign: Some("center".to_string()),
-        ..Default::default()
+/// 색상 값을 정규화해 적용 (해석할 수 없는 값은 선언을 무시)
+fn apply_color(field: &mut Option<String>, value: &str) {
+    if let Some(color) = normalize_color(value) {
+        *field = Some(color);
+    }
+}
+
+/// `border`, `border-top` 등 테두리 단축 속성 (두께, 스타일, 색상) 전개
+fn apply_border_shorthand(style: &mut RnStyles, property: &str, value: &str, context: &LengthContext) {
+    let mut width = None;
+    let mut border_style = None;
+    let mut color = None;
+
+    for part in split_css_value(value) {
+        let lower = part.to_ascii_lowercase();
+        if BORDER_STYLE_KEYWORDS.contains(&lower.as_str()) {
+            border_style = Some(lower);
+        } else if width.is_none() && parse_border_width(part, context).is_some() {
+            width = Some(part);
+        } else {
+            color = Some(part);
+        }
+    }
+
+    // 스타일이 없거나 none이면 테두리가 그려지지 않음
+    let visible = border_style.as_deref().is_some_and(|s| s != "none" && s != "hidden");
+    let width = if visible { width.unwrap_or("medium") } else { "0" };
+
+    match property.strip_prefix("border-") {
+        Some(side) => {
+            apply_css_property_to_rn_style(style, &format!("border-{}-width", side), width, context);
+            if let Some(color) = color {
+                apply_css_property_to_rn_style(style, &format!("border-{}-color", side), color, context);
+            }
+        }
+        None => {
+            apply_box_shorthand(style, "border-width", width, context);
+            if let Some(color) = color {
+                apply_box_shorthand(style, "border-color", color, context);
+            }
+        }
+    }
+
+    if visible {
+        style.borderStyle = border_style;
+    }
+}
+
+/// `font` 단축 속성 전개 (`[style] [variant] [weight] size[/line-height] family`)
+fn apply_font_shorthand(style: &mut RnStyles, value: &str, context: &LengthContext) {
+    let parts = split_css_value(value);
+
+    // 시스템 폰트 키워드 (caption, menu 등)는 지원하지 않음
+    if parts.len() < 2 {
+        return;
+    }
+
+    let mut font_style = "normal";
+    let mut font_weight = "normal";
+    let mut index = 0;
+
+    while let Some(part) = parts.get(index) {
+        match part.to_ascii_lowercase().as_str() {
+            "italic" | "oblique" => font_style = part,
+            "bold" | "bolder" | "lighter" | "100" | "200" | "300" | "400" | "500" | "600" | "700" | "800" | "900" => {
+                font_weight = part;
+            }
+            // font-variant, font-stretch 및 명시적 normal은 무시
+            "normal" | "small-caps" | "condensed" | "expanded" | "semi-condensed" | "semi-expanded" => {}
+            _ => break,
+        }
+        index += 1;
+    }
+
+    let Some(size) = parts.get(index) else {
+        return;
+    };
+    let mut family_start = index + 1;
+
+    // `1.2em/1.4` 형태의 줄 높이
+    let (size, line_height) = match size.split_once('/') {
+        Some((size, line_height)) if !line_height.is_empty() => (size, Some(line_height)),
+        Some((size, _)) => {
+            family_start += 1;
+            (size, parts.get(index + 1).copied())
+        }
+        None if parts.get(index + 1) == Some(&"/") => {
+            family_start += 2;
+            (*size, parts.get(index + 2).copied())
+        }
+        None => (*size, None),
+    };
+
+    let family = parts[family_start.min(parts.len())..].join(" ");
+    if family.is_empty() {
+        return;
+    }
+
+    apply_css_property_to_rn_style(style, "font-style", font_style, context);
+    apply_css_property_to_rn_style(style, "font-weight", font_weight, context);
+    apply_css_property_to_rn_style(style, "font-size", size, context);
+    if let Some(line_height) = line_height {
+        // 줄 높이의 em/% 단위는 방금 정한 폰트 크기 기준
+        let context = LengthContext { font_size: style.fontSize.unwrap_or(context.font_size), ..*context };
+        apply_css_property_to_rn_style(style, "line-height", line_height, &context);
+    }
+    apply_css_property_to_rn_style(style, "font-family", &family, context);
+}
+
+/// `list-style` 단축 속성에서 마커 유형만 추출 (위치와 이미지는 지원하지 않음)
+fn apply_list_style_shorthand(style: &mut RnStyles, value: &str) {
+    let types: Vec<&str> = split_css_value(value).into_iter()
+        .filter(|part| !matches!(part.to_ascii_lowercase().as_str(), "inside" | "outside") && !part.starts_with("url("))
+        .collect();
+    // `none`은 이미지와 유형 모두에 쓰일 수 있으므로 다른 값이 없을 때만 유형으로 사용
+    if let Some(list_style_type) = types.iter().find(|part| !part.eq_ignore_ascii_case("none")).or(types.first()) {
+        style.listStyleType = Some(list_style_type.to_string());
+    }
+}
+
+/// `background` 단축 속성에서 배경색만 추출 (이미지, 위치, 반복 등은 지원하지 않음)
+fn apply_background_shorthand(style: &mut RnStyles, value: &str, context: &LengthContext) {
+    const NON_COLOR_KEYWORDS: [&str; 17] = [
+        "none", "repeat", "no-repeat", "repeat-x", "repeat-y", "space", "round", "scroll", "fixed", "local",
+        "top", "bottom", "left", "right", "center", "cover", "contain",
+    ];
+
+    let color = split_css_value(value).into_iter().rfind(|part| {
+        let lower = part.to_ascii_lowercase();
+        !NON_COLOR_KEYWORDS.contains(&lower.as_str())
+            && !lower.starts_with("url(")
+            && !lower.contains("gradient(")
+            && *part != "/"
+            && parse_dimension(part, context).is_none()
     });
-    
-    styles
+
+    if let Some(color) = color {
+        apply_css_property_to_rn_style(style, "background-color", color, context);
+    }
+}
+
+/// 테두리 두께 값 (thin/medium/thick 키워드 포함)
+fn parse_border_width(value: &str, context: &LengthContext) -> Option<f32> {
+    match value.to_ascii_lowercase().as_str() {
+        "thin" => Some(1.0),
+        "medium" => Some(3.0),
+        "thick" => Some(5.0),
+        _ => resolve_length(value, context),
+    }
+}
+
+/// `font-family` 목록에서 첫 번째 폰트 이름 (RN은 폰트 하나만 지정 가능)
+fn parse_font_family(value: &str) -> Option<String> {
+    value.split(',')
+        .map(|family| family.trim().trim_matches(|c| c == '"' || c == '\'').trim())
+        .find(|family| !family.is_empty())
+        .map(|family| family.to_string())
+}
+
+/// 길이 단위 해석에 필요한 문맥
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LengthContext {
+    /// `rem` 기준 루트 폰트 크기
+    pub root_font_size: f32,
+    /// 부모 요소의 폰트 크기 (`font-size`의 em/% 기준)
+    pub parent_font_size: f32,
+    /// 현재 요소의 폰트 크기 (그 외 속성의 em 기준)
+    pub font_size: f32,
+    pub viewport_width: f32,
+    pub viewport_height: f32,
+}
+
+impl LengthContext {
+    /// 변환 옵션으로부터 루트 요소의 문맥 생성
+    pub fn from_options(options: &ConversionOptions) -> Self {
+        LengthContext {
+            root_font_size: options.root_font_size,
+            parent_font_size: options.root_font_size,
+            font_size: options.root_font_size,
+            viewport_width: options.viewport_width,
+            viewport_height: options.viewport_height,
+        }
+    }
+
+    /// 부모 폰트 크기가 주어진 자식 요소의 문맥
+    pub fn for_child(&self, parent_font_size: f32) -> Self {
+        LengthContext { parent_font_size, font_size: parent_font_size, ..*self }
+    }
+}
+
+impl Default for LengthContext {
+    fn default() -> Self {
+        Self::from_options(&ConversionOptions::default())
+    }
+}
+
+/// 숫자 부분과 단위 부분 분리 (`"1.5em"` → `(1.5, "em")`)
+fn split_number_unit(value: &str) -> Option<(f32, String)> {
+    let value = value.trim();
+    let end = value.char_indices()
+        .find(|(index, c)| !(c.is_ascii_digit() || *c == '.' || ((*c == '-' || *c == '+') && *index == 0)))
+        .map(|(index, _)| index)
+        .unwrap_or(value.len());
+    let number = value[..end].parse().ok()?;
+    Some((number, value[end..].to_ascii_lowercase()))
+}
+
+/// CSS 길이를 픽셀 값으로 변환 (퍼센트는 기준이 속성마다 달라 지원하지 않음)
+pub fn resolve_length(value: &str, context: &LengthContext) -> Option<f32> {
+    let (number, unit) = split_number_unit(value)?;
+    let pixels = match unit.as_str() {
+        // 단위 없는 숫자는 px로 간주
+        "" | "px" => number,
+        "em" => number * context.font_size,
+        "rem" => number * context.root_font_size,
+        // 글꼴 메트릭을 알 수 없으므로 ex/ch는 폰트 크기의 절반으로 근사
+        "ex" | "ch" => number * context.font_size * 0.5,
+        "vw" => number * context.viewport_width / 100.0,
+        "vh" => number * context.viewport_height / 100.0,
+        "vmin" => number * context.viewport_width.min(context.viewport_height) / 100.0,
+        "vmax" => number * context.viewport_width.max(context.viewport_height) / 100.0,
+        "in" => number * 96.0,
+        "cm" => number * 96.0 / 2.54,
+        "mm" => number * 96.0 / 25.4,
+        "q" => number * 96.0 / 101.6,
+        "pt" => number * 96.0 / 72.0,
+        "pc" => number * 16.0,
+        _ => return None,
+    };
+    Some(pixels)
+}
+
+/// 크기 값을 RN 크기로 변환 (퍼센트와 `auto`는 RN이 직접 처리하도록 그대로 전달)
+pub fn parse_dimension(value: &str, context: &LengthContext) -> Option<RnDimension> {
+    let value = value.trim();
+    if value.eq_ignore_ascii_case("auto") {
+        return Some(RnDimension::Auto);
+    }
+    if let Some(percent) = value.strip_suffix('%') {
+        return percent.trim().parse().ok().map(RnDimension::Percent);
+    }
+    resolve_length(value, context).map(RnDimension::Points)
+}
+
+/// `font-size` 값 변환 (em/%는 부모 폰트 크기 기준)
+pub fn parse_font_size(value: &str, context: &LengthContext) -> Option<f32> {
+    let value = value.trim().to_ascii_lowercase();
+    // 키워드 크기는 루트 폰트 크기(medium)에 비례
+    let keyword_scale = match value.as_str() {
+        "xx-small" => Some(9.0 / 16.0),
+        "x-small" => Some(10.0 / 16.0),
+        "small" => Some(13.0 / 16.0),
+        "medium" => Some(1.0),
+        "large" => Some(18.0 / 16.0),
+        "x-large" => Some(24.0 / 16.0),
+        "xx-large" => Some(2.0),
+        "xxx-large" => Some(3.0),
+        _ => None,
+    };
+    if let Some(scale) = keyword_scale {
+        return Some(context.root_font_size * scale);
+    }
+
+    match value.as_str() {
+        "smaller" => Some(context.parent_font_size / 1.2),
+        "larger" => Some(context.parent_font_size * 1.2),
+        _ => match value.strip_suffix('%') {
+            Some(percent) => percent.trim().parse::<f32>().ok().map(|p| context.parent_font_size * p / 100.0),
+            None => resolve_length(&value, &LengthContext { font_size: context.parent_font_size, ..*context }),
+        },
+    }
+}
+
+/// `line-height` 값 변환: (픽셀 값, 단위 없는 배수)
+///
+/// RN의 `lineHeight`는 픽셀 값이므로 현재 폰트 크기로 계산하고,
+/// 단위 없는 배수는 자식이 자신의 폰트 크기로 다시 계산할 수 있도록 함께 반환합니다.
+pub fn parse_line_height(value: &str, context: &LengthContext) -> (Option<f32>, Option<f32>) {
+    let value = value.trim();
+    if value.eq_ignore_ascii_case("normal") {
+        return (None, None);
+    }
+    if let Ok(multiplier) = value.parse::<f32>() {
+        return (Some(multiplier * context.font_size), Some(multiplier));
+    }
+    if let Some(percent) = value.strip_suffix('%') {
+        return (percent.trim().parse::<f32>().ok().map(|p| context.font_size * p / 100.0), None);
+    }
+    (resolve_length(value, context), None)
+}
+
+/// CSS 크기 값을 픽셀 단위로 변환 (기본 문맥 기준)
+pub fn parse_size_value(value: &str) -> Option<f32> {
+    resolve_length(value, &LengthContext::default())
+}
+
+/// 기본 EPUB 스타일 생성 (user agent 스타일시트의 선택자별 스타일)
+pub fn create_default_epub_styles() -> HashMap<String, RnStyles> {
+    Stylesheet::user_agent().to_style_map()
 }
 
 /// 기본값이 적용된 RnStyles 생성
@@ -426,7 +1086,7 @@ impl Default for RnStyles {
         RnStyles {
             fontSize: None, fontWeight: None, fontFamily: None, fontStyle: None,
             color: None, textAlign: None, textDecorationLine: None, textTransform: None,
-            lineHeight: None, textIndent: None, backgroundColor: None, opacity: None,
+            lineHeight: None, lineHeightMultiplier: None, textIndent: None, listStyleType: None, whiteSpace: None, backgroundColor: None, opacity: None,
             marginTop: None, marginBottom: None, marginLeft: None, marginRight: None,
             paddingTop: None, paddingBottom: None, paddingLeft: None, paddingRight: None,
             width: None, height: None, minWidth: None, maxWidth: None, minHeight: None, maxHeight: None,
@@ -453,8 +1113,13 @@ pub fn merge_styles(class_style: Option<RnStyles>, inline_style: Option<RnStyles
             if inline.textAlign.is_some() { class.textAlign = inline.textAlign; }
             if inline.textDecorationLine.is_some() { class.textDecorationLine = inline.textDecorationLine; }
             if inline.textTransform.is_some() { class.textTransform = inline.textTransform; }
-            if inline.lineHeight.is_some() { class.lineHeight = inline.lineHeight; }
+            if inline.lineHeight.is_some() {
+                class.lineHeight = inline.lineHeight;
+                class.lineHeightMultiplier = inline.lineHeightMultiplier;
+            }
             if inline.textIndent.is_some() { class.textIndent = inline.textIndent; }
+            if inline.listStyleType.is_some() { class.listStyleType = inline.listStyleType; }
+            if inline.whiteSpace.is_some() { class.whiteSpace = inline.whiteSpace; }
             
             // 배경 및 색상
             if inline.backgroundColor.is_some() { class.backgroundColor = inline.backgroundColor; }
@@ -521,4 +1186,140 @@ pub fn merge_styles(class_style: Option<RnStyles>, inline_style: Option<RnStyles
         (None, Some(inline)) => Some(inline),
         (None, None) => None,
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_semicolons_inside_urls_and_strings() {
+        let declarations = parse_css_declarations(r#"background: url("a;b.png") red; content: "x;y"; color: blue"#);
+        let values: Vec<(&str, &str)> = declarations.iter()
+            .map(|declaration| (declaration.property.as_str(), declaration.value.as_str()))
+            .collect();
+        assert_eq!(values, [("background", r#"url("a;b.png") red"#), ("content", r#""x;y""#), ("color", "blue")]);
+    }
+
+    #[test]
+    fn parses_base64_font_face_sources() {
+        let stylesheet = Stylesheet::parse_with(
+            r#"@font-face {
+                font-family: "Inline";
+                src: url(data:font/woff2;base64,AAAA) format("woff2"), url("data:font/ttf;base64,BBBB"), url(../fonts/a.ttf);
+            }"#,
+            "OEBPS/css/main.css",
+            &ConversionOptions::default(),
+            &mut |_| None,
+        );
+        let font_face = &stylesheet.font_faces[0];
+        assert_eq!(font_face.family, "Inline");
+        assert_eq!(font_face.sources, [
+            "data:font/woff2;base64,AAAA",
+            "data:font/ttf;base64,BBBB",
+            "OEBPS/fonts/a.ttf",
+        ]);
+    }
+
+    #[test]
+    fn font_size_is_relative_to_parent_and_root() {
+        // 루트 16px, 부모 20px
+        let context = LengthContext::default().for_child(20.0);
+        assert_eq!(parse_font_size("1.5em", &context), Some(30.0));
+        assert_eq!(parse_font_size("50%", &context), Some(10.0));
+        assert_eq!(parse_font_size("2rem", &context), Some(32.0));
+        assert_eq!(parse_font_size("12px", &context), Some(12.0));
+        assert_eq!(parse_font_size("larger", &context), Some(24.0));
+        // 키워드는 루트 크기 기준
+        assert_eq!(parse_font_size("x-large", &context), Some(24.0));
+    }
+
+    #[test]
+    fn line_height_keeps_unitless_multiplier() {
+        let context = LengthContext { font_size: 20.0, ..LengthContext::default() };
+        assert_eq!(parse_line_height("1.5", &context), (Some(30.0), Some(1.5)));
+        assert_eq!(parse_line_height("150%", &context), (Some(30.0), None));
+        assert_eq!(parse_line_height("2em", &context), (Some(40.0), None));
+        assert_eq!(parse_line_height("1rem", &context), (Some(16.0), None));
+        assert_eq!(parse_line_height("normal", &context), (None, None));
+    }
+
+    /// 선언 목록을 기본 문맥(루트 16px)에서 적용한 스타일
+    fn style_of(declarations: &str) -> RnStyles {
+        let mut style = RnStyles::default();
+        apply_css_declarations(&mut style, parse_css_declarations(declarations).iter(), &LengthContext::default());
+        style
+    }
+
+    #[test]
+    fn expands_font_shorthand() {
+        let style = style_of("font: italic bold 1.2em/1.4 Georgia, serif");
+        assert_eq!(style.fontStyle.as_deref(), Some("italic"));
+        assert_eq!(style.fontWeight.as_deref(), Some("bold"));
+        assert_eq!(style.fontSize, Some(19.2));
+        assert_eq!(style.lineHeightMultiplier, Some(1.4));
+        assert!((style.lineHeight.unwrap() - 19.2 * 1.4).abs() < 0.001);
+        assert_eq!(style.fontFamily.as_deref(), Some("Georgia"));
+
+        // 스타일과 굵기가 생략되면 normal로 재설정
+        let style = style_of("font-weight: bold; font: 12px / 20px serif");
+        assert_eq!(style.fontWeight.as_deref(), Some("normal"));
+        assert_eq!(style.fontSize, Some(12.0));
+        assert_eq!(style.lineHeight, Some(20.0));
+        // 크기와 글꼴이 없으면 무시
+        assert_eq!(style_of("font: bold"), RnStyles::default());
+    }
+
+    #[test]
+    fn expands_box_shorthands() {
+        let style = style_of("margin: 0 auto");
+        assert_eq!(
+            [style.marginTop, style.marginRight, style.marginBottom, style.marginLeft],
+            [Some(RnDimension::Points(0.0)), Some(RnDimension::Auto), Some(RnDimension::Points(0.0)), Some(RnDimension::Auto)],
+        );
+        let style = style_of("padding: 1px 2px 3px");
+        assert_eq!(
+            [style.paddingTop, style.paddingRight, style.paddingBottom, style.paddingLeft],
+            [Some(RnDimension::Points(1.0)), Some(RnDimension::Points(2.0)), Some(RnDimension::Points(3.0)), Some(RnDimension::Points(2.0))],
+        );
+    }
+
+    #[test]
+    fn expands_border_shorthands() {
+        let style = style_of("border: 2px solid #ff0000");
+        assert_eq!(style.borderWidth, Some(2.0));
+        assert_eq!(style.borderColor.as_deref(), Some("#ff0000"));
+        assert_eq!(style.borderStyle.as_deref(), Some("solid"));
+        assert_eq!(style.borderTopWidth, None);
+
+        // 스타일이 없으면 테두리가 그려지지 않음
+        assert_eq!(style_of("border: 2px #ff0000").borderWidth, Some(0.0));
+        // 두께를 생략하면 medium
+        let style = style_of("border-bottom: dashed");
+        assert_eq!(style.borderBottomWidth, Some(3.0));
+        assert_eq!(style.borderStyle.as_deref(), Some("dashed"));
+    }
+
+    #[test]
+    fn takes_color_from_background_shorthand() {
+        assert_eq!(style_of("background: url(bg.png) no-repeat center / cover #ffeedd").backgroundColor.as_deref(), Some("#ffeedd"));
+        assert_eq!(style_of("background: none").backgroundColor, None);
+    }
+
+    #[test]
+    fn takes_type_from_list_style_shorthand() {
+        assert_eq!(style_of("list-style: square inside").listStyleType.as_deref(), Some("square"));
+        assert_eq!(style_of("list-style: none url(dot.png)").listStyleType.as_deref(), Some("none"));
+        assert_eq!(style_of("list-style: outside none lower-roman").listStyleType.as_deref(), Some("lower-roman"));
+    }
+
+    #[test]
+    fn combines_text_decoration_lines() {
+        let line = |declarations: &str| style_of(declarations).textDecorationLine;
+        assert_eq!(line("text-decoration: underline line-through").as_deref(), Some("underline line-through"));
+        assert_eq!(line("text-decoration: line-through underline wavy red").as_deref(), Some("underline line-through"));
+        assert_eq!(line("text-decoration-line: line-through").as_deref(), Some("line-through"));
+        assert_eq!(line("text-decoration: none").as_deref(), Some("none"));
+        // 지원하지 않는 값은 앞 선언을 유지
+        assert_eq!(line("text-decoration: underline; text-decoration: overline").as_deref(), Some("underline"));
+    }
+}
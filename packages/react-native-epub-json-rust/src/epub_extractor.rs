@@ -1,7 +1,9 @@
 use epub::doc::EpubDoc;
-use std::collections::HashMap;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
 use base64::{Engine as _, engine::general_purpose};
 use std::io::{Read, Seek, Cursor};
+use scraper::Html;
 
 // WASM 환경에서 console.log 사용을 위한 매크로
 #[cfg(target_arch = "wasm32")]
@@ -19,70 +21,280 @@ macro_rules! log {
 }
 
 use crate::types::*;
-use crate::css_parser::parse_css_to_rn_styles;
-use crate::html_parser::{parse_html_to_rn_nodes, extract_title_from_html};
+use crate::error::EpubError;
+use crate::css_parser::{css_import_urls, FontFace, Stylesheet};
+use crate::utils::{percent_decode, percent_encode_path, resolve_href, resolve_relative_path};
+use crate::fonts::{apply_font_mapping, deobfuscate_font, font_mime_type, font_name, parse_encryption};
+use crate::opf_parser::OpfPackage;
+use crate::image_size::image_dimensions;
+use crate::cover::extract_cover;
+use crate::metadata::{
+    dc_values, parse_collections, parse_contributors, parse_identifiers, parse_modified, parse_titles, Refinements,
+};
+use crate::nav_parser::{flatten_entries, parse_nav_document, parse_ncx_nav_map, parse_ncx_page_list, NavEntry};
+use crate::html_parser::{
+    collect_anchors, collect_note_elements, collect_note_ids, convert_document_to_rn_nodes, document_stylesheet,
+    extract_title_from_html, redirect_links_into_notes, ConvertContext,
+};
+use crate::whitespace::LineState;
 
 /// EPUB 바이트에서 완전한 정보를 추출하여 React Native 구조로 변환
-pub fn extract_complete_epub_info_from_bytes(epub_bytes: &[u8]) -> Result<CompleteEpubInfo, Box<dyn std::error::Error>> {
+pub fn extract_complete_epub_info_from_bytes(epub_bytes: &[u8]) -> Result<CompleteEpubInfo, EpubError> {
+    extract_complete_epub_info_from_bytes_with_options(epub_bytes, &ConversionOptions::default())
+}
+
+/// 변환 옵션을 지정해 EPUB 바이트를 변환
+pub fn extract_complete_epub_info_from_bytes_with_options(
+    epub_bytes: &[u8],
+    options: &ConversionOptions,
+) -> Result<CompleteEpubInfo, EpubError> {
     let cursor = Cursor::new(epub_bytes);
     let mut doc = EpubDoc::from_reader(cursor)?;
-    extract_epub_data(&mut doc)
+    extract_epub_data(&mut doc, options)
 }
 
 /// EPUB 파일에서 완전한 정보를 추출하여 React Native 구조로 변환
-pub fn extract_complete_epub_info(epub_path: &str) -> Result<CompleteEpubInfo, Box<dyn std::error::Error>> {
+pub fn extract_complete_epub_info(epub_path: &str) -> Result<CompleteEpubInfo, EpubError> {
+    extract_complete_epub_info_with_options(epub_path, &ConversionOptions::default())
+}
+
+/// 변환 옵션을 지정해 EPUB 파일을 변환
+pub fn extract_complete_epub_info_with_options(
+    epub_path: &str,
+    options: &ConversionOptions,
+) -> Result<CompleteEpubInfo, EpubError> {
     let mut doc = EpubDoc::new(epub_path)?;
-    extract_epub_data(&mut doc)
+    extract_epub_data(&mut doc, options)
+}
+
+/// EPUB 바이트에서 표지 이미지만 추출 (챕터는 변환하지 않음)
+pub fn extract_cover_image_from_bytes(epub_bytes: &[u8]) -> Result<Option<CoverImage>, EpubError> {
+    let mut doc = EpubDoc::from_reader(Cursor::new(epub_bytes))?;
+    let package = read_package(&mut doc, &mut Vec::new());
+    Ok(extract_cover(&mut doc, &package))
+}
+
+/// EPUB 파일에서 표지 이미지만 추출 (챕터는 변환하지 않음)
+pub fn extract_cover_image(epub_path: &str) -> Result<Option<CoverImage>, EpubError> {
+    let mut doc = EpubDoc::new(epub_path)?;
+    let package = read_package(&mut doc, &mut Vec::new());
+    Ok(extract_cover(&mut doc, &package))
+}
+
+/// EPUB 바이트에서 메타데이터, 표지, 목차, spine만 추출 (챕터와 이미지는 변환하지 않음)
+pub fn extract_epub_summary_from_bytes(epub_bytes: &[u8]) -> Result<EpubSummary, EpubError> {
+    let mut doc = EpubDoc::from_reader(Cursor::new(epub_bytes))?;
+    extract_summary_data(&mut doc)
+}
+
+/// EPUB 파일에서 메타데이터, 표지, 목차, spine만 추출 (챕터와 이미지는 변환하지 않음)
+pub fn extract_epub_summary(epub_path: &str) -> Result<EpubSummary, EpubError> {
+    let mut doc = EpubDoc::new(epub_path)?;
+    extract_summary_data(&mut doc)
+}
+
+/// OPF 패키지 문서 읽기
+///
+/// 아카이브를 열 때 container.xml과 OPF는 이미 확인되었으므로, 여기서 읽거나 해석하지 못하면
+/// 경고를 남기고 빈 패키지로 계속합니다 (manifest 순서, 표지 등 보조 정보만 빠짐).
+fn read_package<R: Read + Seek>(doc: &mut EpubDoc<R>, warnings: &mut Vec<ConversionWarning>) -> OpfPackage {
+    let root_file = doc.root_file.clone();
+    let root_path = root_file.display().to_string();
+    let package = doc.get_resource_by_path(&root_file)
+        .ok_or_else(|| EpubError::ResourceMissing(root_path.clone()))
+        .and_then(|opf| decode_text(opf, &root_path))
+        .and_then(|opf| OpfPackage::parse(&opf)
+            .ok_or_else(|| EpubError::Container(format!("malformed package document: {}", root_path))));
+    package.unwrap_or_else(|error| {
+        log!("   ⚠️  Failed to read package document: {}", error);
+        warnings.push(ConversionWarning::from(&error));
+        OpfPackage::default()
+    })
 }
 
 /// 공통 EPUB 데이터 추출 로직
-fn extract_epub_data<R: Read + Seek>(doc: &mut EpubDoc<R>) -> Result<CompleteEpubInfo, Box<dyn std::error::Error>> {
+fn extract_epub_data<R: Read + Seek>(
+    doc: &mut EpubDoc<R>,
+    options: &ConversionOptions,
+) -> Result<CompleteEpubInfo, EpubError> {
+    
+    // Spine 정보
+    let spine_items = doc.spine.clone();
+    let spine = spine_info(&spine_items);
+    
+    // 변환을 중단하지 않는 문제
+    let mut warnings = Vec::new();
+    
+    // OPF 패키지 문서 (manifest 순서 등)
+    let package = read_package(doc, &mut warnings);
     
     // 메타데이터 추출
-    let metadata = extract_metadata(doc);
+    let metadata = extract_metadata(doc, &package);
+    
+    // 표지 이미지
+    let cover = extract_cover(doc, &package);
+    
+    // CSS 스타일 추출
+    let resources_map = doc.resources.clone();
+    let (stylesheets, stylesheet) = extract_styles(doc, &resources_map, &package, options, &mut warnings);
+    
+    // 이미지 추출 (출력 방식에 따라 인라인, 참조 또는 파일)
+    let (images, assets, image_files) = extract_images(doc, &resources_map, options);
+    let image_sources: HashMap<String, String> = match options.image_mode {
+        ImageMode::Reference => images.keys().map(|id| (id.clone(), id.clone())).collect(),
+        ImageMode::Inline | ImageMode::Directory => images.clone(),
+    };
+    let cover = cover.map(|cover| match images.get(&cover.id) {
+        Some(source) if options.image_mode == ImageMode::Directory => CoverImage { source: source.clone(), ..cover },
+        _ => cover,
+    });
+    
+    // 챕터 문서 읽기 (챕터의 `<style>`에 선언된 @font-face도 폰트로 추출하기 위해 먼저 읽음)
+    let documents = read_chapters(doc, &spine_items, &resources_map, &stylesheets, options, &mut warnings);
+    
+    // @font-face 폰트 추출 (난독화 해제)
+    let font_faces = stylesheet.font_faces.iter()
+        .chain(documents.iter().flat_map(|chapter| &chapter.stylesheet.font_faces));
+    let fonts = extract_fonts(doc, &resources_map, font_faces, &package);
+    
+    // 내부 링크 해석용: spine 문서 경로 → spine 인덱스
+    let spine_paths = spine_paths(&spine_items, &resources_map);
+    
+    // 이미지 참조 해석용: 이미지 경로 → 이미지 ID
+    let image_paths: HashMap<String, String> = assets.iter()
+        .map(|(id, asset)| (asset.path.clone(), id.clone()))
+        .collect();
+    
+    // 챕터 내용을 RN 노드 구조로 변환 (`path`와 `unresolved`는 챕터마다 새로 지정)
+    let unresolved = RefCell::new(Vec::new());
+    let context = ConvertContext {
+        stylesheet: &stylesheet,
+        images: &image_sources,
+        image_paths: &image_paths,
+        assets: &assets,
+        fonts: &fonts,
+        options,
+        path: "",
+        spine_paths: &spine_paths,
+        unresolved: &unresolved,
+        line_state: &Cell::new(LineState::LineStart),
+        note_ids: &HashSet::new(),
+        note_elements: &HashMap::new(),
+        notes: &RefCell::new(Vec::new()),
+        list_ordinal: None,
+    };
+    let chapters = extract_chapters(documents, &context);
+    
+    // TOC, 랜드마크, 페이지 목록 (챕터와 앵커로 해석)
+    let targets = NavTargets {
+        spine_paths: &spine_paths,
+        chapter_spines: chapters.iter().map(|chapter| chapter.spine_index).collect(),
+        chapters: Some(&chapters),
+    };
+    let (toc, landmarks, page_list) = extract_navigation(doc, &resources_map, &package, &targets);
     
     // 구조 정보
     let structure = EpubStructure {
         spine_count: doc.spine.len(),
         resource_count: doc.resources.len(),
-        toc_count: doc.toc.len(),
+        toc_count: toc.len(),
     };
     
-    // TOC 정보
-    let toc: Vec<TocItem> = doc.toc.iter().map(|item| TocItem {
-        label: item.label.clone(),
-        content_path: item.content.display().to_string(),
-    }).collect();
+    // 선택자별 스타일 맵 (참고용 출력)
+    let mut styles = stylesheet.to_style_map();
+    for style in styles.values_mut() {
+        apply_font_mapping(style, &fonts);
+    }
     
-    // Spine 정보
+    Ok(CompleteEpubInfo {
+        metadata,
+        cover,
+        structure,
+        toc,
+        landmarks,
+        page_list,
+        spine,
+        styles,
+        images,
+        assets,
+        fonts,
+        chapters,
+        warnings,
+        image_files,
+    })
+}
+
+/// 목록/색인용 요약 추출 로직 (스타일, 이미지, 폰트, 챕터 본문은 읽지 않음)
+fn extract_summary_data<R: Read + Seek>(doc: &mut EpubDoc<R>) -> Result<EpubSummary, EpubError> {
     let spine_items = doc.spine.clone();
-    let spine: Vec<SpineItemInfo> = spine_items.iter().map(|item| SpineItemInfo {
-        idref: item.idref.clone(),
-        id: item.id.clone(),
-        properties: item.properties.clone(),
-        linear: item.linear,
-    }).collect();
+    let spine = spine_info(&spine_items);
     
-    // CSS 스타일 및 이미지 추출
+    let package = read_package(doc, &mut Vec::new());
+    let metadata = extract_metadata(doc, &package);
+    let cover = extract_cover(doc, &package);
+    
+    // 챕터는 변환하지 않으므로 목차의 프래그먼트는 확인 없이 앵커로 사용
     let resources_map = doc.resources.clone();
-    let (styles, images) = extract_styles_and_images(doc, &resources_map);
+    let spine_paths = spine_paths(&spine_items, &resources_map);
+    let targets = NavTargets {
+        spine_paths: &spine_paths,
+        chapter_spines: chapter_items(&spine_items, &resources_map).into_iter().map(|(index, _)| index).collect(),
+        chapters: None,
+    };
+    let (toc, _, _) = extract_navigation(doc, &resources_map, &package, &targets);
     
-    // 챕터 내용을 RN 노드 구조로 변환
-    let chapters = extract_chapters(doc, &spine_items, &resources_map, &styles, &images);
+    let structure = EpubStructure {
+        spine_count: doc.spine.len(),
+        resource_count: doc.resources.len(),
+        toc_count: toc.len(),
+    };
     
-    Ok(CompleteEpubInfo {
+    Ok(EpubSummary {
         metadata,
+        cover,
         structure,
         toc,
         spine,
-        styles,
-        images,
-        chapters,
     })
 }
 
+/// Spine 항목 정보
+fn spine_info(spine_items: &[epub::doc::SpineItem]) -> Vec<SpineItemInfo> {
+    spine_items.iter().map(|item| SpineItemInfo {
+        idref: item.idref.clone(),
+        id: item.id.clone(),
+        properties: item.properties.clone(),
+        linear: item.linear,
+    }).collect()
+}
+
+/// spine 문서 경로 (퍼센트 디코딩) → spine 인덱스
+fn spine_paths(
+    spine_items: &[epub::doc::SpineItem],
+    resources_map: &HashMap<String, (std::path::PathBuf, String)>
+) -> HashMap<String, usize> {
+    spine_items.iter().enumerate()
+        .filter_map(|(index, item)| Some((percent_decode(&resources_map.get(&item.idref)?.0.display().to_string()), index)))
+        .collect()
+}
+
+/// 챕터로 변환되는 spine 항목: (spine 인덱스, idref)
+fn chapter_items(
+    spine_items: &[epub::doc::SpineItem],
+    resources_map: &HashMap<String, (std::path::PathBuf, String)>
+) -> Vec<(usize, String)> {
+    spine_items.iter().enumerate()
+        .filter(|(_, item)| resources_map.get(&item.idref).is_some_and(|resource| resource.1 == "application/xhtml+xml"))
+        .map(|(index, item)| (index, item.idref.clone()))
+        .collect()
+}
+
 /// 메타데이터 추출
-fn extract_metadata<R: Read + Seek>(doc: &mut EpubDoc<R>) -> EpubMetadata {
+///
+/// 기존 단일 값 필드는 각 항목의 첫 번째 값이며, 목록 필드에는 OPF의 모든 값과 refinement가 담깁니다.
+fn extract_metadata<R: Read + Seek>(doc: &mut EpubDoc<R>, package: &OpfPackage) -> EpubMetadata {
+    let refinements = Refinements::new(&package.metadata);
+    
     EpubMetadata {
         title: doc.mdata("title"),
         author: doc.mdata("creator"),
@@ -93,97 +305,514 @@ fn extract_metadata<R: Read + Seek>(doc: &mut EpubDoc<R>) -> EpubMetadata {
         identifier: doc.mdata("identifier"),
         rights: doc.mdata("rights"),
         subject: doc.mdata("subject"),
+        titles: parse_titles(&package.metadata, &refinements),
+        creators: parse_contributors(&package.metadata, "creator", &refinements),
+        contributors: parse_contributors(&package.metadata, "contributor", &refinements),
+        subjects: dc_values(&package.metadata, "subject"),
+        languages: dc_values(&package.metadata, "language"),
+        identifiers: parse_identifiers(&package.metadata, &refinements),
+        collections: parse_collections(&package.metadata, &refinements),
+        modified: parse_modified(&package.metadata),
     }
 }
 
 /// CSS 스타일과 이미지 추출
-fn extract_styles_and_images<R: Read + Seek>(
+///
+/// 반환값: (경로별 스타일시트, 모든 스타일시트를 합친 전체 스타일시트, 이미지)
+fn extract_styles<R: Read + Seek>(
     doc: &mut EpubDoc<R>, 
-    resources_map: &HashMap<String, (std::path::PathBuf, String)>
-) -> (HashMap<String, RnStyles>, HashMap<String, String>) {
-    let mut styles = HashMap::new();
-    let mut images = HashMap::new();
+    resources_map: &HashMap<String, (std::path::PathBuf, String)>,
+    package: &OpfPackage,
+    options: &ConversionOptions,
+    warnings: &mut Vec<ConversionWarning>,
+) -> (HashMap<String, Stylesheet>, Stylesheet) {
+    // 기본 EPUB 스타일 위에 저자 스타일시트를 쌓음
+    let mut stylesheet = Stylesheet::user_agent();
     
-    let mut css_files = Vec::new();
-    let mut image_files = Vec::new();
-    let mut other_files = Vec::new();
+    let mut css_files: Vec<&String> = resources_map.iter()
+        .filter(|(_, (_, mime_type))| mime_type == "text/css")
+        .map(|(id, _)| id)
+        .collect();
     
-    // 리소스 타입별 분류
-    for (id, (path, mime_type)) in resources_map.iter() {
-        if mime_type == "text/css" {
-            css_files.push((id, path));
-        } else if mime_type.starts_with("image/") {
-            image_files.push((id, path, mime_type));
-        } else {
-            other_files.push((id, mime_type));
-        }
-    }
-    
-    // CSS 파일들을 RN 스타일로 변환
-    for (index, (id, path)) in css_files.iter().enumerate() {
+    // CSS 파일들을 manifest 순서대로 읽기 (캐스케이드의 소스 순서)
+    css_files.sort_by_key(|id| (package.manifest_index(id).unwrap_or(usize::MAX), id.to_string()));
+    // 경로는 모두 퍼센트 디코딩해 비교 (챕터의 <link>와 @import도 디코딩한 경로로 찾음)
+    let mut css_sources: Vec<(String, String)> = Vec::new();
+    for id in css_files {
+        let path = percent_decode(&resources_map[id].0.display().to_string());
         if let Some((css_content, _)) = doc.get_resource_str(id) {
-            if css_content.len() > 0 {
-                let parsed_styles = parse_css_to_rn_styles(&css_content);
-                let styles_count = parsed_styles.len();
-                styles.extend(parsed_styles);
-                
-            } else {
+            if css_content.is_empty() {
                 log!("   ⚠️  CSS file is empty");
             }
+            css_sources.push((path, css_content));
         } else {
             log!("   ❌ Failed to read CSS file: {}", id);
+            warnings.push(ConversionWarning::from(&EpubError::Css(path.clone())));
         }
     }
+    let css_by_path: HashMap<&str, &str> = css_sources.iter()
+        .map(|(path, css)| (path.as_str(), css.as_str()))
+        .collect();
     
+    // 다른 스타일시트가 @import하는 파일은 import 위치에서만 적용
+    let imported: HashSet<String> = css_sources.iter()
+        .flat_map(|(path, css)| css_import_urls(css).into_iter().map(move |url| percent_decode(&resolve_relative_path(path, &url))))
+        .collect();
     
-    for (index, (id, path, mime_type)) in image_files.iter().enumerate() {
-        if let Some((data, _)) = doc.get_resource(id) {
-            let base64_data = general_purpose::STANDARD.encode(&data);
-            let data_uri = format!("data:{};base64,{}", mime_type, base64_data);
-            let size_kb = data.len() / 1024;
-            
-            images.insert(id.to_string(), data_uri);
-        } else {
+    // 챕터가 <link>로 참조할 수 있도록 파일별로 보관 (@import는 해석된 상태)
+    let mut stylesheets = HashMap::new();
+    for (path, _) in &css_sources {
+        let loaded = load_stylesheet(path, &css_by_path, options, &mut vec![path.clone()]);
+        if !imported.contains(path) {
+            stylesheet.extend(loaded.clone());
+        }
+        stylesheets.insert(path.clone(), loaded);
+    }
+    
+    (stylesheets, stylesheet)
+}
+
+/// (이미지 ID → data URI 또는 URL, 이미지 ID → 파일 정보, Directory 모드에서 쓸 이미지)
+type ExtractedImages = (HashMap<String, String>, HashMap<String, ImageAsset>, Vec<ImageFile>);
+
+/// 이미지 리소스 추출
+///
+/// Directory 모드에서는 URL을 반환하고, 이미지 바이트는 아카이브 경로와 함께 따로 모읍니다.
+fn extract_images<R: Read + Seek>(
+    doc: &mut EpubDoc<R>,
+    resources_map: &HashMap<String, (std::path::PathBuf, String)>,
+    options: &ConversionOptions
+) -> ExtractedImages {
+    let mut images = HashMap::new();
+    let mut assets = HashMap::new();
+    let mut files = Vec::new();
+    
+    for (id, (path, mime_type)) in resources_map.iter().filter(|(_, (_, mime_type))| mime_type.starts_with("image/")) {
+        let Some((data, _)) = doc.get_resource(id) else {
             log!("   ❌ Failed to read image: {}", id);
+            continue;
+        };
+        // manifest의 href는 퍼센트 인코딩되어 있을 수 있음
+        let path_str = percent_decode(&path.display().to_string());
+        
+        let dimensions = image_dimensions(&data);
+        if dimensions.is_none() {
+            log!("   ⚠️  Unknown image size: {}", path_str);
         }
+        let asset = ImageAsset {
+            path: path_str.clone(),
+            mime_type: mime_type.clone(),
+            size: data.len(),
+            width: dimensions.map(|(width, _)| width),
+            height: dimensions.map(|(_, height)| height),
+            aspect_ratio: dimensions.map(|(width, height)| width / height),
+            file: None,
+        };
+        
+        let source = if options.image_mode == ImageMode::Directory {
+            // 이미지 디렉터리 밖을 가리키는 경로는 쓰지 않음
+            let relative = std::path::Path::new(&path_str);
+            if !relative.components().all(|component| matches!(component, std::path::Component::Normal(_))) {
+                log!("   ⚠️  Skipping image with unsafe path: {}", path_str);
+                continue;
+            }
+            let source = image_url(&options.image_url_template, id, &path_str);
+            files.push(ImageFile { id: id.clone(), path: path_str, data });
+            source
+        } else {
+            format!("data:{};base64,{}", mime_type, general_purpose::STANDARD.encode(&data))
+        };
+        
+        images.insert(id.clone(), source);
+        assets.insert(id.clone(), asset);
     }
     
-    (styles, images)
+    (images, assets, files)
 }
 
-/// 챕터들을 RN 노드 구조로 변환
-fn extract_chapters<R: Read + Seek>(
+/// URL 템플릿의 `{path}`, `{id}`, `{filename}` 치환 (경로는 퍼센트 인코딩)
+fn image_url(template: &str, id: &str, path: &str) -> String {
+    let path = percent_encode_path(path);
+    let filename = path.rsplit('/').next().unwrap_or(&path);
+    template.replace("{path}", &path)
+        .replace("{id}", id)
+        .replace("{filename}", filename)
+}
+
+/// `@font-face`가 참조하는 폰트 리소스 추출
+///
+/// `font_faces`는 전체 스타일시트와 각 챕터의 `<style>`에 선언된 것을 모두 받습니다.
+/// 여러 `src` 중 React Native에서 바로 쓸 수 있는 TrueType/OpenType을 우선하며,
+/// `META-INF/encryption.xml`에 등록된 폰트는 패키지 식별자로 난독화를 해제합니다.
+fn extract_fonts<'a, R: Read + Seek>(
     doc: &mut EpubDoc<R>,
-    spine_items: &[epub::doc::SpineItem],
     resources_map: &HashMap<String, (std::path::PathBuf, String)>,
-    styles: &HashMap<String, RnStyles>,
-    images: &HashMap<String, String>
-) -> Vec<ChapterStructure> {
-    // 챕터 정보 먼저 수집
-    let mut chapter_info: Vec<(usize, String)> = Vec::new();
-    for (index, spine_item) in spine_items.iter().enumerate() {
-        if let Some(resource) = resources_map.get(&spine_item.idref) {
-            if resource.1 == "application/xhtml+xml" {
-                chapter_info.push((index, spine_item.idref.clone()));
+    font_faces: impl Iterator<Item = &'a FontFace>,
+    package: &OpfPackage
+) -> Vec<EmbeddedFont> {
+    let encryption = doc.get_resource_str_by_path("META-INF/encryption.xml")
+        .map(|xml| parse_encryption(&xml))
+        .unwrap_or_default();
+    
+    // 아카이브 경로 → (manifest ID, MIME 타입)
+    let resources_by_path: HashMap<String, (&String, &String)> = resources_map.iter()
+        .map(|(id, (path, mime_type))| (path.display().to_string(), (id, mime_type)))
+        .collect();
+    
+    let mut fonts: Vec<EmbeddedFont> = Vec::new();
+    for font_face in font_faces {
+        let name = font_name(&font_face.family, &font_face.weight, &font_face.style);
+        // 같은 패밀리/두께/스타일이 여러 번 선언되면 (unicode-range 분할 등) 첫 번째만 사용
+        if fonts.iter().any(|font| font.name == name) {
+            continue;
+        }
+        
+        let mut sources: Vec<&String> = font_face.sources.iter()
+            .filter(|source| source.starts_with("data:") || resources_by_path.contains_key(source.as_str()))
+            .collect();
+        sources.sort_by_key(|source| !matches!(font_mime_type(source), Some("font/ttf" | "font/otf")));
+        let Some(source) = sources.first() else {
+            log!("   ⚠️  Font not found in manifest: {}", font_face.family);
+            continue;
+        };
+        
+        let (mime_type, data_uri) = if let Some(data) = source.strip_prefix("data:") {
+            let mime_type = data.split([';', ',']).next().unwrap_or_default();
+            (mime_type.to_string(), source.to_string())
+        } else {
+            let (id, manifest_type) = resources_by_path[source.as_str()];
+            let Some((mut data, _)) = doc.get_resource(id) else {
+                log!("   ❌ Failed to read font: {}", id);
+                continue;
+            };
+            if let Some(obfuscation) = encryption.get(source.as_str())
+                && !deobfuscate_font(&mut data, *obfuscation, &package.identifiers)
+            {
+                log!("   ⚠️  Failed to deobfuscate font: {}", source);
+                continue;
             }
+            let mime_type = font_mime_type(source).unwrap_or(manifest_type).to_string();
+            let data_uri = format!("data:{};base64,{}", mime_type, general_purpose::STANDARD.encode(&data));
+            (mime_type, data_uri)
+        };
+        
+        fonts.push(EmbeddedFont {
+            name,
+            family: font_face.family.clone(),
+            weight: font_face.weight.clone(),
+            style: font_face.style.clone(),
+            mime_type,
+            source: data_uri,
+        });
+    }
+    
+    fonts
+}
+
+/// 스타일시트를 파싱하며 `@import`를 manifest의 CSS 파일로 재귀적으로 해석
+///
+/// `path`와 `css_by_path`의 키는 퍼센트 디코딩된 아카이브 경로입니다.
+fn load_stylesheet(
+    path: &str,
+    css_by_path: &HashMap<&str, &str>,
+    options: &ConversionOptions,
+    import_stack: &mut Vec<String>,
+) -> Stylesheet {
+    let css = css_by_path.get(path).copied().unwrap_or_default();
+    Stylesheet::parse_with(css, path, options, &mut |url| {
+        let target = percent_decode(&resolve_relative_path(path, url));
+        if import_stack.contains(&target) {
+            log!("   ⚠️  Circular @import: {}", target);
+            return None;
         }
+        if !css_by_path.contains_key(target.as_str()) {
+            log!("   ⚠️  @import target not in manifest: {}", target);
+            return None;
+        }
+        import_stack.push(target.clone());
+        let imported = load_stylesheet(&target, css_by_path, options, import_stack);
+        import_stack.pop();
+        Some(imported)
+    })
+}
+
+/// 읽어서 파싱한 챕터 문서
+struct ChapterDocument {
+    spine_index: usize,
+    idref: String,
+    path: String,
+    html: String,
+    document: Html,
+    /// 챕터가 `<link>`로 참조하는 스타일시트와 `<style>` 블록
+    stylesheet: Stylesheet,
+}
+
+/// spine 순서대로 챕터 문서를 읽고 각 챕터에 적용되는 스타일시트를 구성
+///
+/// 읽을 수 없는 챕터는 경고를 남기고 건너뜁니다.
+fn read_chapters<R: Read + Seek>(
+    doc: &mut EpubDoc<R>,
+    spine_items: &[epub::doc::SpineItem],
+    resources_map: &HashMap<String, (std::path::PathBuf, String)>,
+    stylesheets: &HashMap<String, Stylesheet>,
+    options: &ConversionOptions,
+    warnings: &mut Vec<ConversionWarning>,
+) -> Vec<ChapterDocument> {
+    let mut documents = Vec::new();
+    for (spine_index, idref) in chapter_items(spine_items, resources_map) {
+        let path = resources_map[&idref].0.display().to_string();
+        let html = doc.get_resource(&idref)
+            .ok_or_else(|| EpubError::ResourceMissing(path.clone()))
+            .and_then(|(bytes, _)| decode_text(bytes, &path));
+        let html = match html {
+            Ok(html) => html,
+            Err(error) => {
+                log!("   ❌ Skipping chapter: {}", error);
+                warnings.push(ConversionWarning::from(&error));
+                continue;
+            }
+        };
+        let document = Html::parse_document(&html);
+        let stylesheet = document_stylesheet(&document, &path, stylesheets, options);
+        documents.push(ChapterDocument { spine_index, idref, path, html, document, stylesheet });
     }
+    documents
+}
+
+/// 챕터들을 RN 노드 구조로 변환
+///
+/// 각 챕터에는 그 챕터가 `<link>`로 참조하는 스타일시트와 `<style>` 블록만 적용됩니다.
+fn extract_chapters(documents: Vec<ChapterDocument>, context: &ConvertContext) -> Vec<ChapterStructure> {
+    // 주석은 다른 챕터에서 참조될 수 있으므로 모든 챕터의 주석 요소를 먼저 수집
+    let note_ids: HashSet<(usize, String)> = documents.iter()
+        .flat_map(|chapter| collect_note_ids(&chapter.document, &ConvertContext { path: &chapter.path, ..*context }))
+        .collect();
     
     // 챕터 내용을 RN 노드 구조로 변환
     let mut chapters = Vec::new();
-    for (spine_index, idref) in chapter_info {
-        if let Some((html_content, _)) = doc.get_resource_str(&idref) {
-            let rn_node = parse_html_to_rn_nodes(&html_content, styles, images);
-            let title = extract_title_from_html(&html_content);
-            
-            chapters.push(ChapterStructure {
-                spine_index,
-                idref,
-                title,
-                content: rn_node,
-            });
-        }
+    for chapter in documents {
+        let unresolved = RefCell::new(Vec::new());
+        let notes = RefCell::new(Vec::new());
+        let note_elements = collect_note_elements(&chapter.document, &ConvertContext { path: &chapter.path, note_ids: &note_ids, ..*context });
+        let chapter_context = ConvertContext {
+            stylesheet: &chapter.stylesheet,
+            path: &chapter.path,
+            unresolved: &unresolved,
+            note_ids: &note_ids,
+            note_elements: &note_elements,
+            notes: &notes,
+            ..*context
+        };
+        let rn_node = convert_document_to_rn_nodes(&chapter.document, &chapter_context);
+        let title = extract_title_from_html(&chapter.html);
+        let anchors = collect_anchors(&rn_node);
+        
+        chapters.push(ChapterStructure {
+            spine_index: chapter.spine_index,
+            idref: chapter.idref,
+            title,
+            content: rn_node,
+            anchors,
+            unresolved_references: unresolved.into_inner(),
+            notes: notes.into_inner(),
+        });
+    }
+    if context.options.hide_notes {
+        redirect_links_into_notes(&mut chapters);
     }
     
     chapters
-} 
\ No newline at end of file
+}
+
+/// 내비게이션 정보 추출: (목차, 랜드마크, 페이지 목록)
+///
+/// EPUB3 내비게이션 문서의 `<nav>`를 우선하고, 없으면 목차와 페이지 목록은 NCX,
+/// 랜드마크는 OPF `<guide>`를 사용합니다.
+fn extract_navigation<R: Read + Seek>(
+    doc: &mut EpubDoc<R>,
+    resources_map: &HashMap<String, (std::path::PathBuf, String)>,
+    package: &OpfPackage,
+    targets: &NavTargets
+) -> (Vec<TocItem>, Vec<Landmark>, Vec<PageTarget>) {
+    // (아카이브 경로, 내용)
+    let mut read_document = |id: &str| {
+        let path = resources_map.get(id)?.0.display().to_string();
+        let (content, _) = doc.get_resource_str(id)?;
+        Some((path, content))
+    };
+    let nav_document = package.nav_item().and_then(|item| read_document(&item.id));
+    let ncx = package.ncx_id().and_then(&mut read_document);
+    
+    let from_nav = |nav_type: &str| {
+        nav_document.as_ref()
+            .and_then(|(path, html)| parse_nav_document(html, path, nav_type))
+            .filter(|entries| !entries.is_empty())
+    };
+    
+    let toc_entries = from_nav("toc")
+        .or_else(|| ncx.as_ref().and_then(|(path, xml)| parse_ncx_nav_map(xml, path)))
+        .unwrap_or_else(|| {
+            log!("   ⚠️  No table of contents found");
+            Vec::new()
+        });
+    let toc = resolve_toc(&toc_entries, 0, &mut 1, targets);
+    
+    let landmarks = match from_nav("landmarks") {
+        Some(entries) => flatten_entries(entries).into_iter()
+            .filter_map(|entry| {
+                let content_path = entry.href?;
+                let (chapter_index, anchor) = resolve_target(&content_path, targets);
+                Some(Landmark {
+                    landmark_type: entry.epub_type.unwrap_or_default(),
+                    label: entry.label,
+                    content_path,
+                    chapter_index,
+                    anchor,
+                })
+            })
+            .collect(),
+        None => {
+            let opf_path = doc.root_file.display().to_string();
+            package.guide.iter().map(|reference| {
+                let content_path = resolve_href(&opf_path, &reference.href);
+                let (chapter_index, anchor) = resolve_target(&content_path, targets);
+                Landmark {
+                    landmark_type: guide_landmark_type(&reference.reference_type),
+                    label: reference.title.clone().unwrap_or_else(|| reference.reference_type.clone()),
+                    content_path,
+                    chapter_index,
+                    anchor,
+                }
+            }).collect()
+        }
+    };
+    
+    let page_entries = from_nav("page-list")
+        .or_else(|| ncx.as_ref().and_then(|(path, xml)| parse_ncx_page_list(xml, path)))
+        .unwrap_or_default();
+    let page_list = flatten_entries(page_entries).into_iter()
+        .filter_map(|entry| {
+            let content_path = entry.href?;
+            let (chapter_index, anchor) = resolve_target(&content_path, targets);
+            Some(PageTarget { label: entry.label, content_path, chapter_index, anchor })
+        })
+        .collect();
+    
+    (toc, landmarks, page_list)
+}
+
+/// EPUB2 `<guide>` 유형을 EPUB3 구조 어휘로 변환
+fn guide_landmark_type(reference_type: &str) -> String {
+    match reference_type {
+        "text" => "bodymatter".to_string(),
+        "title-page" => "titlepage".to_string(),
+        "acknowledgements" => "acknowledgments".to_string(),
+        "other.ms-coverimage-standard" | "other.ms-coverimage" => "cover".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// 내비게이션 항목을 챕터로 해석하기 위한 정보
+struct NavTargets<'a> {
+    spine_paths: &'a HashMap<String, usize>,
+    /// 챕터 순서대로의 spine 인덱스
+    chapter_spines: Vec<usize>,
+    /// 변환된 챕터 (앵커 확인용). None이면 프래그먼트를 확인 없이 앵커로 사용
+    chapters: Option<&'a [ChapterStructure]>,
+}
+
+/// 목차 항목을 챕터 인덱스와 앵커로 해석
+///
+/// `next_order`는 `playOrder`가 없는 항목에 매길 다음 읽기 순서입니다.
+fn resolve_toc(
+    entries: &[NavEntry],
+    depth: usize,
+    next_order: &mut usize,
+    targets: &NavTargets
+) -> Vec<TocItem> {
+    entries.iter().map(|entry| {
+        let content_path = entry.href.clone().unwrap_or_default();
+        let (chapter_index, anchor) = resolve_target(&content_path, targets);
+        
+        let play_order = entry.play_order.unwrap_or(*next_order);
+        *next_order += 1;
+        
+        TocItem {
+            label: entry.label.clone(),
+            content_path,
+            chapter_index,
+            anchor,
+            depth,
+            play_order,
+            children: resolve_toc(&entry.children, depth + 1, next_order, targets),
+        }
+    }).collect()
+}
+
+/// 아카이브 경로(프래그먼트 포함)를 (챕터 인덱스, 앵커)로 해석
+///
+/// 문서는 spine을 거쳐 `chapters` 인덱스로, 프래그먼트는 그 챕터에 있는 앵커일 때만 앵커로 바뀝니다.
+fn resolve_target(content_path: &str, targets: &NavTargets) -> (Option<usize>, Option<String>) {
+    let (path, fragment) = match content_path.split_once('#') {
+        Some((path, fragment)) => (path, Some(fragment)),
+        None => (content_path, None),
+    };
+    
+    let chapter_index = targets.spine_paths.get(&percent_decode(&resolve_relative_path("", path)))
+        .and_then(|spine_index| targets.chapter_spines.iter().position(|index| index == spine_index));
+    let anchor = match (chapter_index, fragment) {
+        (Some(index), Some(fragment)) if !fragment.is_empty() => {
+            let found = targets.chapters
+                .is_none_or(|chapters| chapters[index].anchors.iter().any(|anchor| anchor.id == fragment));
+            if !found {
+                log!("   ⚠️  Anchor not found: {}", content_path);
+            }
+            Some(fragment.to_string()).filter(|_| found)
+        }
+        _ => None,
+    };
+    
+    (chapter_index, anchor)
+}
+
+/// 텍스트 리소스 디코딩 (UTF-8, BOM이 있는 UTF-16)
+fn decode_text(bytes: Vec<u8>, path: &str) -> Result<String, EpubError> {
+    let utf16 = |bytes: &[u8], from_bytes: fn([u8; 2]) -> u16| {
+        let units: Vec<u16> = bytes.chunks_exact(2).map(|pair| from_bytes([pair[0], pair[1]])).collect();
+        String::from_utf16(&units).map_err(|_| EpubError::Encoding(format!("invalid UTF-16 in {}", path)))
+    };
+    
+    match bytes.as_slice() {
+        [0xEF, 0xBB, 0xBF, rest @ ..] => String::from_utf8(rest.to_vec())
+            .map_err(|_| EpubError::Encoding(format!("invalid UTF-8 in {}", path))),
+        [0xFF, 0xFE, rest @ ..] => utf16(rest, u16::from_le_bytes),
+        [0xFE, 0xFF, rest @ ..] => utf16(rest, u16::from_be_bytes),
+        _ => String::from_utf8(bytes).map_err(|_| EpubError::Encoding(format!("invalid UTF-8 in {}", path))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_percent_encoded_imports() {
+        let css_by_path = HashMap::from([
+            ("OEBPS/css/main.css", r#"@import "base%20styles.css"; p { color: #ff0000 }"#),
+            ("OEBPS/css/base styles.css", "h1 { color: #00ff00 }"),
+        ]);
+        let path = "OEBPS/css/main.css";
+        let stylesheet = load_stylesheet(path, &css_by_path, &ConversionOptions::default(), &mut vec![path.to_string()]);
+        assert_eq!(stylesheet.rules.len(), 2);
+    }
+
+    #[test]
+    fn stops_at_circular_imports() {
+        let css_by_path = HashMap::from([
+            ("a.css", r#"@import "b.css"; p { color: #ff0000 }"#),
+            ("b.css", r#"@import "a.css"; h1 { color: #00ff00 }"#),
+        ]);
+        let stylesheet = load_stylesheet("a.css", &css_by_path, &ConversionOptions::default(), &mut vec!["a.css".to_string()]);
+        assert_eq!(stylesheet.rules.len(), 2);
+    }
+}
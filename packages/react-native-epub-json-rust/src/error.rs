@@ -0,0 +1,103 @@
+use std::fmt;
+use epub::archive::ArchiveError;
+use epub::doc::DocError;
+use crate::types::ConversionWarning;
+
+/// 변환 중 발생할 수 있는 오류
+///
+/// WASM에서는 `code`와 `message` 필드를 가진 JS `Error` 객체로 전달됩니다 ([`EpubError::code`] 참고).
+#[derive(Debug)]
+pub enum EpubError {
+    /// ZIP 아카이브가 손상되었거나 EPUB이 아님
+    Archive(String),
+    /// `META-INF/container.xml` 또는 OPF 패키지 문서가 없거나 잘못됨
+    Container(String),
+    /// manifest/spine이 참조하는 리소스가 아카이브에 없음 (아카이브 경로)
+    ResourceMissing(String),
+    /// 텍스트 리소스의 문자 인코딩을 해석할 수 없음
+    Encoding(String),
+    /// 스타일시트를 읽을 수 없음 (아카이브 경로)
+    Css(String),
+    /// 결과를 JSON/JS 값으로 직렬화하지 못함
+    Serialization(String),
+    /// 변환 옵션이 잘못됨
+    InvalidOptions(String),
+    /// 파일 입출력 실패
+    Io(std::io::Error),
+}
+
+impl EpubError {
+    /// 호출 측에서 분기할 수 있는 고정 오류 코드
+    pub fn code(&self) -> &'static str {
+        match self {
+            EpubError::Archive(_) => "ARCHIVE_ERROR",
+            EpubError::Container(_) => "CONTAINER_ERROR",
+            EpubError::ResourceMissing(_) => "RESOURCE_MISSING",
+            EpubError::Encoding(_) => "ENCODING_ERROR",
+            EpubError::Css(_) => "CSS_ERROR",
+            EpubError::Serialization(_) => "SERIALIZATION_ERROR",
+            EpubError::InvalidOptions(_) => "INVALID_OPTIONS",
+            EpubError::Io(_) => "IO_ERROR",
+        }
+    }
+}
+
+impl fmt::Display for EpubError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EpubError::Archive(message) => write!(f, "invalid EPUB archive: {}", message),
+            EpubError::Container(message) => write!(f, "invalid container or package document: {}", message),
+            EpubError::ResourceMissing(path) => write!(f, "resource not found in archive: {}", path),
+            EpubError::Encoding(message) => write!(f, "unsupported text encoding: {}", message),
+            EpubError::Css(path) => write!(f, "stylesheet could not be read: {}", path),
+            EpubError::Serialization(message) => write!(f, "serialization failed: {}", message),
+            EpubError::InvalidOptions(message) => write!(f, "invalid conversion options: {}", message),
+            EpubError::Io(error) => write!(f, "I/O error: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for EpubError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EpubError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<&EpubError> for ConversionWarning {
+    fn from(error: &EpubError) -> Self {
+        ConversionWarning { code: error.code().to_string(), message: error.to_string() }
+    }
+}
+
+impl From<DocError> for EpubError {
+    fn from(error: DocError) -> Self {
+        match error {
+            DocError::ArchiveError(ArchiveError::IO(error)) | DocError::IOError(error) => EpubError::Io(error),
+            // 아카이브를 연 직후에는 container.xml과 OPF만 읽으므로 파일 없음은 패키지 구조 오류
+            DocError::ArchiveError(ArchiveError::Zip(zip::result::ZipError::FileNotFound)) => {
+                EpubError::Container("container.xml or package document not found".to_string())
+            }
+            DocError::ArchiveError(ArchiveError::Zip(error)) => EpubError::Archive(error.to_string()),
+            DocError::ArchiveError(error @ (ArchiveError::Utf8(_) | ArchiveError::PathUtf8)) => {
+                EpubError::Encoding(error.to_string())
+            }
+            DocError::XmlError(error) => EpubError::Container(error.to_string()),
+            DocError::InvalidEpub => EpubError::Container("invalid EPUB package".to_string()),
+        }
+    }
+}
+
+impl From<std::io::Error> for EpubError {
+    fn from(error: std::io::Error) -> Self {
+        EpubError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for EpubError {
+    fn from(error: serde_json::Error) -> Self {
+        EpubError::Serialization(error.to_string())
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use sha1::{Digest, Sha1};
+use crate::types::{EmbeddedFont, RnStyles};
+use crate::opf_parser::XmlElement;
+use crate::utils::resolve_relative_path;
+
+/// IDPF 폰트 난독화 알고리즘 (EPUB OCF)
+const IDPF_OBFUSCATION: &str = "http://www.idpf.org/2008/embedding";
+/// Adobe 폰트 난독화 알고리즘
+const ADOBE_OBFUSCATION: &str = "http://ns.adobe.com/pdf/enc#RC";
+
+/// 폰트 난독화 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontObfuscation {
+    Idpf,
+    Adobe,
+}
+
+impl FontObfuscation {
+    fn from_algorithm(algorithm: &str) -> Option<Self> {
+        match algorithm.trim() {
+            IDPF_OBFUSCATION => Some(FontObfuscation::Idpf),
+            ADOBE_OBFUSCATION => Some(FontObfuscation::Adobe),
+            _ => None,
+        }
+    }
+}
+
+/// `META-INF/encryption.xml`에서 난독화된 리소스 목록 추출 (아카이브 경로 → 방식)
+pub fn parse_encryption(xml: &str) -> HashMap<String, FontObfuscation> {
+    let Some(root) = XmlElement::parse(xml) else {
+        return HashMap::new();
+    };
+
+    root.children_named("EncryptedData")
+        .filter_map(|data| {
+            let algorithm = data.child("EncryptionMethod")?.attr("Algorithm")?;
+            let uri = data.child("CipherData")?.child("CipherReference")?.attr("URI")?;
+            // URI는 컨테이너 루트 기준
+            Some((resolve_relative_path("", uri), FontObfuscation::from_algorithm(algorithm)?))
+        })
+        .collect()
+}
+
+/// 난독화된 폰트 데이터를 원래대로 복원
+///
+/// - IDPF: 공백을 제거한 고유 식별자의 SHA-1(20바이트)로 앞 1040바이트를 XOR
+/// - Adobe: `urn:uuid:` 식별자의 UUID 16바이트로 앞 1024바이트를 XOR
+///
+/// 키를 만들 수 없으면 (Adobe 방식인데 UUID 식별자가 없는 경우 등) false를 반환합니다.
+pub fn deobfuscate_font(data: &mut [u8], obfuscation: FontObfuscation, identifiers: &[String]) -> bool {
+    let (key, length) = match obfuscation {
+        FontObfuscation::Idpf => {
+            let Some(identifier) = identifiers.first() else {
+                return false;
+            };
+            let identifier: String = identifier.chars()
+                .filter(|c| !matches!(c, ' ' | '\t' | '\r' | '\n'))
+                .collect();
+            (Sha1::digest(identifier.as_bytes()).to_vec(), 1040)
+        }
+        FontObfuscation::Adobe => {
+            let Some(key) = identifiers.iter().find_map(|identifier| adobe_key(identifier)) else {
+                return false;
+            };
+            (key, 1024)
+        }
+    };
+
+    for (index, byte) in data.iter_mut().take(length).enumerate() {
+        *byte ^= key[index % key.len()];
+    }
+    true
+}
+
+/// `urn:uuid:xxxxxxxx-xxxx-...` 식별자에서 16바이트 키 추출
+fn adobe_key(identifier: &str) -> Option<Vec<u8>> {
+    let identifier = identifier.trim();
+    let uuid = identifier.strip_prefix("urn:uuid:").unwrap_or(identifier);
+    let hex: String = uuid.chars().filter(|c| *c != '-').collect();
+    if hex.len() != 32 {
+        return None;
+    }
+    (0..16).map(|index| u8::from_str_radix(&hex[index * 2..index * 2 + 2], 16).ok()).collect()
+}
+
+/// 폰트 파일 확장자로 MIME 타입 추정
+pub fn font_mime_type(path: &str) -> Option<&'static str> {
+    let extension = path.rsplit('.').next()?.to_ascii_lowercase();
+    match extension.as_str() {
+        "ttf" => Some("font/ttf"),
+        "otf" => Some("font/otf"),
+        "woff" => Some("font/woff"),
+        "woff2" => Some("font/woff2"),
+        _ => None,
+    }
+}
+
+/// 패밀리/두께/스타일로부터 안정적인 폰트 로드 이름 생성 (예: `Charis SIL` bold italic → `CharisSIL-BoldItalic`)
+pub fn font_name(family: &str, weight: &str, style: &str) -> String {
+    let base: String = family.chars().filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_').collect();
+    let base = if base.is_empty() { "EmbeddedFont".to_string() } else { base };
+
+    let weight_suffix = match font_weight_value(weight) {
+        400 => String::new(),
+        700 => "Bold".to_string(),
+        weight => format!("W{}", weight),
+    };
+    let style_suffix = if style == "normal" { "" } else { "Italic" };
+
+    if weight_suffix.is_empty() && style_suffix.is_empty() {
+        base
+    } else {
+        format!("{}-{}{}", base, weight_suffix, style_suffix)
+    }
+}
+
+/// `font-weight` 값을 숫자로 변환
+fn font_weight_value(weight: &str) -> u32 {
+    match weight.trim().to_ascii_lowercase().as_str() {
+        "normal" => 400,
+        "bold" | "bolder" => 700,
+        "lighter" => 300,
+        weight => weight.split_whitespace().next().and_then(|w| w.parse().ok()).unwrap_or(400),
+    }
+}
+
+/// 스타일의 `fontFamily`가 내장 폰트를 가리키면 두께/스타일에 가장 가까운 폰트의 로드 이름으로 변경
+///
+/// 이미 로드 이름으로 바뀐 값(상속된 값 등)도 같은 패밀리로 인식해 다시 고릅니다.
+pub fn apply_font_mapping(style: &mut RnStyles, fonts: &[EmbeddedFont]) {
+    let Some(font_family) = style.fontFamily.as_deref() else {
+        return;
+    };
+    let Some(family) = fonts.iter()
+        .find(|font| font.family.eq_ignore_ascii_case(font_family) || font.name == font_family)
+        .map(|font| font.family.clone())
+    else {
+        return;
+    };
+
+    let weight = font_weight_value(style.fontWeight.as_deref().unwrap_or("normal"));
+    let italic = style.fontStyle.as_deref().is_some_and(|s| s != "normal");
+
+    let best = fonts.iter()
+        .filter(|font| font.family == family)
+        .min_by_key(|font| ((font.style != "normal") != italic, font_weight_value(&font.weight).abs_diff(weight)));
+    if let Some(best) = best {
+        style.fontFamily = Some(best.name.clone());
+    }
+}
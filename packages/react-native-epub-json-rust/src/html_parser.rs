@@ -1,7 +1,17 @@
-use std::collections::HashMap;
-use scraper::{Html, Selector};
-use crate::types::{RnNode, RnStyles};
-use crate::css_parser::{merge_styles};
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use ego_tree::NodeId;
+use scraper::{ElementRef, Html, Selector};
+use crate::types::{ChapterAnchor, ChapterStructure, ConversionOptions, EmbeddedFont, ImageAsset, LinkTarget, Note, NoteKind, RnDimension, RnNode, RnStyles};
+use crate::fonts::apply_font_mapping;
+use crate::media::media_query_matches;
+use crate::utils::{percent_decode, resolve_relative_path, url_scheme};
+use crate::css_parser::{LengthContext, Stylesheet};
+use crate::cascade::{compute_element_style, inherit_text_styles, inline_text_styles, resolve_current_color, text_styles};
+use crate::lists::{list_item_ordinals, list_marker};
+use crate::notes::{is_note_section, is_noteref, note_kind};
+use crate::tables::{column_widths, layout_table, table_span, width_attribute};
+use crate::whitespace::{collapse_whitespace, end_line, is_block_element, LineState};
 
 // WASM 환경에서 console.log 사용을 위한 매크로
 #[cfg(target_arch = "wasm32")]
@@ -18,71 +28,57 @@ macro_rules! log {
     }
 }
 
-/// 인라인 CSS 선언을 파싱하는 래퍼 함수
-fn parse_css_declarations(declarations: &str) -> RnStyles {
-    match crate::css_parser::parse_css_declarations_with_cssparser(declarations) {
-        Ok(style) => style,
-        Err(_) => {
-            // 폴백: 간단한 파싱
-            let mut style = RnStyles::default();
-            
-            for declaration in declarations.split(';') {
-                let parts: Vec<&str> = declaration.split(':').collect();
-                if parts.len() == 2 {
-                    let property = parts[0].trim();
-                    let value = parts[1].trim();
-                    apply_simple_css_property(&mut style, property, value);
-                }
-            }
-            
-            style
-        }
-    }
+/// HTML 변환 중 모든 요소가 공유하는 입력
+pub struct ConvertContext<'a> {
+    pub stylesheet: &'a Stylesheet,
+    /// 이미지 ID → `Image` 노드의 `source` 값 (이미지 출력 방식에 따라 data URI, ID 또는 URL)
+    pub images: &'a HashMap<String, String>,
+    /// 이미지의 아카이브 경로 (퍼센트 디코딩) → 이미지 ID
+    pub image_paths: &'a HashMap<String, String>,
+    /// 이미지 ID → 파일 정보 (고유 크기)
+    pub assets: &'a HashMap<String, ImageAsset>,
+    pub fonts: &'a [EmbeddedFont],
+    pub options: &'a ConversionOptions,
+    /// 변환 중인 문서의 아카이브 경로 (상대 링크의 기준)
+    pub path: &'a str,
+    /// spine 문서의 아카이브 경로 (퍼센트 디코딩) → spine 인덱스
+    pub spine_paths: &'a HashMap<String, usize>,
+    /// 리소스를 찾지 못한 참조 (원래 `src` 값)
+    pub unresolved: &'a RefCell<Vec<String>>,
+    /// 직전 인라인 내용이 끝난 상태 (요소 경계를 넘는 공백 병합)
+    pub line_state: &'a Cell<LineState>,
+    /// 책 전체의 주석 요소 (spine 인덱스, id): 주석으로 표시된 요소와 주석 참조의 대상
+    pub note_ids: &'a HashSet<(usize, String)>,
+    /// 변환 중인 문서에서 주석으로 추출할 요소 ([`collect_note_elements`] 참고)
+    pub note_elements: &'a HashMap<NodeId, (NoteKind, String)>,
+    /// 변환 중 추출한 주석
+    pub notes: &'a RefCell<Vec<Note>>,
+    /// 변환할 `<li>`의 번호 (부모 목록이 자식을 순회하며 지정)
+    pub list_ordinal: Option<i32>,
 }
 
-/// 간단한 CSS 속성 적용 (폴백용)
-fn apply_simple_css_property(style: &mut RnStyles, property: &str, value: &str) {
-    match property {
-        "font-size" => style.fontSize = crate::css_parser::parse_size_value(value),
-        "font-weight" => style.fontWeight = Some(value.to_string()),
-        "font-family" => style.fontFamily = Some(value.trim_matches('"').to_string()),
-        "color" => style.color = Some(value.to_string()),
-        "background-color" => style.backgroundColor = Some(value.to_string()),
-        "text-align" => style.textAlign = Some(value.to_string()),
-        "margin-top" => style.marginTop = crate::css_parser::parse_size_value(value),
-        "margin-bottom" => style.marginBottom = crate::css_parser::parse_size_value(value),
-        "margin-left" => style.marginLeft = crate::css_parser::parse_size_value(value),
-        "margin-right" => style.marginRight = crate::css_parser::parse_size_value(value),
-        "padding-top" => style.paddingTop = crate::css_parser::parse_size_value(value),
-        "padding-bottom" => style.paddingBottom = crate::css_parser::parse_size_value(value),
-        "padding-left" => style.paddingLeft = crate::css_parser::parse_size_value(value),
-        "padding-right" => style.paddingRight = crate::css_parser::parse_size_value(value),
-        "line-height" => style.lineHeight = crate::css_parser::parse_size_value(value),
-        "text-decoration" => {
-            if value.contains("underline") {
-                style.textDecorationLine = Some("underline".to_string());
-            }
-        }
-        "font-style" => style.fontStyle = Some(value.to_string()),
-        _ => {}
-    }
+/// HTML을 React Native 노드 구조로 변환
+pub fn parse_html_to_rn_nodes(html: &str, context: &ConvertContext) -> RnNode {
+    convert_document_to_rn_nodes(&Html::parse_document(html), context)
 }
 
-/// HTML을 React Native 노드 구조로 변환
-pub fn parse_html_to_rn_nodes(html: &str, styles: &HashMap<String, RnStyles>, images: &HashMap<String, String>) -> RnNode {
-    let document = Html::parse_document(html);
+/// 파싱된 HTML 문서를 React Native 노드 구조로 변환
+pub fn convert_document_to_rn_nodes(document: &Html, context: &ConvertContext) -> RnNode {
+    // 루트 요소는 설정된 루트 폰트 크기를 물려받음
+    let root_style = RnStyles { fontSize: Some(context.options.root_font_size), ..Default::default() };
+    context.line_state.set(LineState::LineStart);
     
     // body 태그 찾기
     let body_selector = Selector::parse("body").unwrap();
     if let Some(body) = document.select(&body_selector).next() {
-        convert_element_to_rn_node(body, styles, images)
+        convert_element_to_rn_node(body, context, &root_style)
     } else {
         // body가 없으면 전체 문서를 View로 감싸기
         let mut children = Vec::new();
         let root_selector = Selector::parse("html").unwrap();
         
         for element in document.select(&root_selector) {
-            let child_node = convert_element_to_rn_node(element, styles, images);
+            let child_node = convert_element_to_rn_node(element, context, &root_style);
             children.push(child_node);
         }
         
@@ -90,241 +86,551 @@ pub fn parse_html_to_rn_nodes(html: &str, styles: &HashMap<String, RnStyles>, im
             // 아무것도 없으면 간단한 텍스트 노드
             RnNode::Text { 
                 content: "Empty content".to_string(), 
-                styles: None 
+                styles: None,
+                id: None
             }
         } else {
-            RnNode::View { children, styles: None }
+            RnNode::View { children, styles: None, id: None }
         }
     }
 }
 
 /// HTML 요소를 React Native 노드로 변환
-pub fn convert_element_to_rn_node(element: scraper::ElementRef, styles: &HashMap<String, RnStyles>, images: &HashMap<String, String>) -> RnNode {
+///
+/// `inherited`는 부모 요소의 스타일로, 상속되는 텍스트 속성(색상, 폰트 등)을
+/// 이 요소와 자손의 모든 `Text` 노드에 전달하는 데 사용됩니다.
+pub fn convert_element_to_rn_node(
+    element: scraper::ElementRef,
+    context: &ConvertContext,
+    inherited: &RnStyles,
+) -> RnNode {
     let tag_name = element.value().name();
-    let mut children = Vec::new();
+    let id = element.value().id().map(|id| id.to_string());
+    
+    // 캐스케이드로 계산된 요소 스타일 (상대 단위는 부모 폰트 크기 기준)
+    let parent_font_size = inherited.fontSize.unwrap_or(context.options.root_font_size);
+    let length_context = LengthContext::from_options(context.options).for_child(parent_font_size);
+    let mut computed_style = compute_element_style(element, context.stylesheet, default_class_style(element), &length_context);
+    
+    // 부모로부터 상속된 텍스트 속성까지 채운 스타일 (자식에게 물려줄 값)
+    let mut resolved_style = inherit_text_styles(computed_style.clone().unwrap_or_default(), inherited);
+    apply_font_mapping(&mut resolved_style, context.fonts);
+    // 목록 마커 유형은 상속되지만 Text 노드 스타일에는 포함하지 않음
+    if resolved_style.listStyleType.is_none() {
+        resolved_style.listStyleType = inherited.listStyleType.clone();
+    }
+    if resolved_style.whiteSpace.is_none() {
+        resolved_style.whiteSpace = inherited.whiteSpace.clone();
+    }
+    if let Some(style) = computed_style.as_mut() {
+        // currentColor는 상속까지 반영된 최종 색상 기준
+        resolve_current_color(style, resolved_style.color.as_deref());
+        style.fontFamily = resolved_style.fontFamily.clone().filter(|_| style.fontFamily.is_some());
+        style.listStyleType = None;
+        style.whiteSpace = None;
+    }
+    let computed_style = computed_style.filter(|style| *style != RnStyles::default());
+    let text_style = text_styles(&resolved_style);
     
-    // 자식 노드들 처리
+    // 자식 노드들 처리 (블록의 시작과 끝, <br>에서 줄이 끝남)
+    // 목록 항목 번호는 목록마다 한 번에 계산해 `<li>` 자식에 순서대로 넘김
+    let mut ordinals = list_item_ordinals(element).into_iter();
+    let mut children = Vec::new();
     for child in element.children() {
         if let Some(text) = child.value().as_text() {
-            let content = text.trim();
+            let content = collapse_whitespace(text, resolved_style.whiteSpace.as_deref(), context.line_state);
             if !content.is_empty() {
                 children.push(RnNode::Text { 
-                    content: content.to_string(), 
-                    styles: None 
+                    content, 
+                    styles: text_style.clone(),
+                    id: None
                 });
             }
-        } else if child.value().as_element().is_some() {
-            if let Some(child_element) = scraper::ElementRef::wrap(child) {
-                let child_node = convert_element_to_rn_node(child_element, styles, images);
-                children.push(child_node);
+        } else if let Some(child_element) = scraper::ElementRef::wrap(child) {
+            let child_name = child_element.value().name();
+            if is_block_element(child_element) || child_name == "br" {
+                end_line(&mut children, context.line_state);
+            }
+            let child_context = ConvertContext {
+                list_ordinal: if child_name == "li" { ordinals.next() } else { None },
+                ..*context
+            };
+            let child_node = convert_element_to_rn_node(child_element, &child_context, &resolved_style);
+            // 주석은 추출하고, 본문에서 숨기는 옵션이면 주석과 주석 구역을 흐름에서 뺌
+            if let Some((kind, id)) = note_of(child_element, context) {
+                if context.options.hide_notes {
+                    context.notes.borrow_mut().push(Note { id, kind, content: child_node });
+                    continue;
+                }
+                context.notes.borrow_mut().push(Note { id, kind, content: child_node.clone() });
+            }
+            if context.options.hide_notes && is_note_section(child_element) {
+                continue;
+            }
+            children.push(child_node);
+            if matches!(child_name, "img" | "image") {
+                context.line_state.set(LineState::Content);
             }
         }
     }
+    if is_block_element(element) {
+        end_line(&mut children, context.line_state);
+    }
     
-    // 인라인 스타일 추출
-    let inline_style = extract_inline_styles(element);
-    
-    // CSS 클래스 스타일 추출
-    let class_style = extract_class_styles(element, styles);
-    
-    // 스타일 병합
-    let merged_style = merge_styles(class_style, inline_style);
+    // 블록 요소(또는 블록 내용을 가진 인라인 요소)는 연속된 인라인 내용을 한 Span으로 묶어
+    // 문단이 하나의 텍스트로 줄바꿈되도록 함
+    let block = is_block_element(element) || children.iter().any(|child| !is_inline_node(child));
+    let children = if block { group_inline_runs(children, &text_style) } else { children };
     
     match tag_name {
-        "p" => {
-            // 문단은 기본 상하 마진 추가
-            let mut paragraph_style = merged_style.unwrap_or_else(|| create_empty_style());
-            if paragraph_style.marginTop.is_none() {
-                paragraph_style.marginTop = Some(8.0);
-            }
-            if paragraph_style.marginBottom.is_none() {
-                paragraph_style.marginBottom = Some(8.0);
-            }
-            RnNode::View { children, styles: Some(paragraph_style) }
-        }
-        "div" | "section" | "article" | "header" | "footer" => {
-            RnNode::View { children, styles: merged_style }
-        }
         "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
-            let mut heading_style = merged_style.unwrap_or_else(|| create_empty_style());
-            
-            // 헤딩 기본 스타일
-            heading_style.fontWeight = Some("bold".to_string());
-            heading_style.fontSize = Some(match tag_name {
-                "h1" => 24.0,
-                "h2" => 20.0,
-                "h3" => 18.0,
-                "h4" => 16.0,
-                "h5" => 14.0,
-                "h6" => 12.0,
-                _ => 16.0,
-            });
-            
-            let styled_children = apply_text_style_to_children(children, &heading_style);
-            
-            // 헤딩은 일반적으로 블록 요소이므로 항상 View로 감싸기
-            RnNode::View { children: styled_children, styles: extract_layout_styles(&heading_style) }
+            // 헤딩은 일반적으로 블록 요소이므로 항상 View로 감싸기 (텍스트 스타일은 자식 Text에 상속됨)
+            RnNode::View { children, styles: extract_layout_styles(&resolved_style), id }
         }
-        "img" => {
-            if let Some(src) = element.value().attr("src") {
+        "img" | "image" => {
+            if let Some(src) = image_src(element) {
                 let alt = element.value().attr("alt").map(|s| s.to_string());
                 
-                // 이미지 소스가 리소스 맵에 있는지 확인
-                let source = if let Some(image_data) = images.get(src) {
-                    image_data.clone()
-                } else {
-                    // 상대 경로에서 파일명만 추출해서 다시 시도
-                    let filename = src.split('/').last().unwrap_or(src);
-                    images.get(filename).cloned().unwrap_or_else(|| src.to_string())
-                };
+                // 현재 문서 기준으로 manifest의 이미지를 찾고, 없으면 원래 값을 유지
+                let (source, asset) = resolve_image(src, context).unwrap_or_else(|| {
+                    log!("   ⚠️  Image not found: {} (in {})", src, context.path);
+                    context.unresolved.borrow_mut().push(src.to_string());
+                    (src.to_string(), None)
+                });
+                let (width, height, aspect_ratio) = image_size(element, asset);
                 
-                RnNode::Image { source, alt, styles: merged_style }
+                RnNode::Image { source, alt, width, height, aspect_ratio, styles: computed_style, id }
             } else {
-                RnNode::View { children, styles: merged_style }
+                container_node(block, children, computed_style, id)
             }
         }
-        "strong" | "b" => {
-            let mut bold_style = merged_style.unwrap_or_else(|| create_empty_style());
-            bold_style.fontWeight = Some("bold".to_string());
-            
-            // 텍스트 스타일은 항상 Text 노드에 적용
-            if children.len() == 1 {
-                if let RnNode::Text { content, .. } = &children[0] {
-                    return RnNode::Text { content: content.clone(), styles: Some(bold_style) };
-                }
-            }
-            
-            // 자식들의 텍스트 노드에 스타일 적용
-            let styled_children = apply_text_style_to_children(children, &bold_style);
-            
-            // 텍스트 스타일만 있으면 View 래핑 없이 텍스트만 반환
-            if styled_children.len() == 1 && matches!(styled_children[0], RnNode::Text { .. }) {
-                return styled_children.into_iter().next().unwrap();
-            }
+        "ul" | "ol" => {
+            // 가장 긴 마커 + 마커와 내용 사이 간격 (글자 폭은 폰트 크기의 0.6배로 근사, 최소 1.5em)
+            let font_size = resolved_style.fontSize.unwrap_or(context.options.root_font_size);
+            let marker_length = children.iter()
+                .filter_map(|child| match child {
+                    RnNode::ListItem { marker, .. } => Some(marker.chars().count()),
+                    _ => None,
+                })
+                .max()
+                .unwrap_or(0);
+            let indent = if marker_length == 0 { 0.0 } else { ((marker_length as f32 * 0.6 + 0.5) * font_size).max(font_size * 1.5) };
             
-            RnNode::View { children: styled_children, styles: extract_layout_styles(&bold_style) }
+            RnNode::List { ordered: tag_name == "ol", indent, children, styles: computed_style, id }
         }
-        "em" | "i" => {
-            let mut italic_style = merged_style.unwrap_or_else(|| create_empty_style());
-            italic_style.fontStyle = Some("italic".to_string());
+        "li" => {
+            let list_style_type = resolved_style.listStyleType.as_deref().unwrap_or("disc");
+            let marker = list_marker(list_style_type, context.list_ordinal.unwrap_or(1));
             
-            if children.len() == 1 {
-                if let RnNode::Text { content, .. } = &children[0] {
-                    return RnNode::Text { content: content.clone(), styles: Some(italic_style) };
+            RnNode::ListItem { marker, marker_styles: text_style.map(Box::new), children, styles: computed_style, id }
+        }
+        "table" => {
+            // 캡션을 앞에 두고, 행 그룹(thead/tbody/tfoot)의 행은 표의 행으로 펼침
+            // (그룹의 id는 첫 행에 두고, 그룹 배경은 배경이 없는 행에 칠함)
+            let mut caption = None;
+            let mut rows = Vec::new();
+            for child in children {
+                match child {
+                    RnNode::TableCaption { .. } if caption.is_none() => caption = Some(child),
+                    RnNode::TableRow { .. } => rows.push(child),
+                    RnNode::View { children, styles: group_styles, id: mut group_id } => {
+                        let background = group_styles.and_then(|style| style.backgroundColor);
+                        for mut row in children {
+                            let RnNode::TableRow { styles, id, .. } = &mut row else { continue };
+                            if id.is_none() {
+                                *id = group_id.take();
+                            }
+                            group_id = None;
+                            if let Some(background) = &background
+                                && styles.as_ref().is_none_or(|style| style.backgroundColor.is_none())
+                            {
+                                styles.get_or_insert_with(RnStyles::default).backgroundColor = Some(background.clone());
+                            }
+                            rows.push(row);
+                        }
+                    }
+                    _ => {}
                 }
             }
             
-            let styled_children = apply_text_style_to_children(children, &italic_style);
-            if styled_children.len() == 1 && matches!(styled_children[0], RnNode::Text { .. }) {
-                return styled_children.into_iter().next().unwrap();
-            }
+            let font_size = resolved_style.fontSize.unwrap_or(context.options.root_font_size);
+            let widths = column_widths(element, context.stylesheet, &length_context.for_child(font_size));
+            let columns = layout_table(&mut rows, widths, font_size);
+            let children = caption.into_iter().chain(rows).collect();
             
-            RnNode::View { children: styled_children, styles: extract_layout_styles(&italic_style) }
+            RnNode::Table { columns, children, styles: computed_style, id }
         }
-        "u" => {
-            let mut underline_style = merged_style.unwrap_or_else(|| create_empty_style());
-            underline_style.textDecorationLine = Some("underline".to_string());
+        // 명시적 줄바꿈
+        "br" => RnNode::Text { content: "\n".to_string(), styles: text_style, id },
+        "caption" => RnNode::TableCaption { children, styles: computed_style, id },
+        "tr" => {
+            let header = element.parent().and_then(scraper::ElementRef::wrap).is_some_and(|parent| parent.value().name() == "thead");
             
-            if children.len() == 1 {
-                if let RnNode::Text { content, .. } = &children[0] {
-                    return RnNode::Text { content: content.clone(), styles: Some(underline_style) };
-                }
+            RnNode::TableRow { header, children, styles: computed_style, id }
+        }
+        "td" | "th" => {
+            // width 속성은 CSS width가 없을 때만 적용 (표현 속성)
+            let mut styles = computed_style;
+            if styles.as_ref().is_none_or(|style| style.width.is_none())
+                && let Some(width) = width_attribute(element, &length_context)
+            {
+                styles.get_or_insert_with(RnStyles::default).width = Some(width);
             }
-            RnNode::View { children, styles: Some(underline_style) }
-        }
-        "blockquote" => {
-            let mut quote_style = merged_style.unwrap_or_else(|| create_empty_style());
-            quote_style.marginLeft = Some(16.0);
-            quote_style.marginRight = Some(16.0);
-            quote_style.marginTop = Some(8.0);
-            quote_style.marginBottom = Some(8.0);
-            quote_style.fontStyle = Some("italic".to_string());
-            RnNode::View { children, styles: Some(quote_style) }
-        }
-        "cite" => {
-            let mut cite_style = merged_style.unwrap_or_else(|| create_empty_style());
-            cite_style.fontStyle = Some("italic".to_string());
             
-            if children.len() == 1 {
-                if let RnNode::Text { content, .. } = &children[0] {
-                    return RnNode::Text { content: content.clone(), styles: Some(cite_style) };
-                }
+            RnNode::TableCell {
+                header: tag_name == "th",
+                column: 0, // layout_table에서 결정
+                colspan: table_span(element, "colspan"),
+                rowspan: table_span(element, "rowspan"),
+                children,
+                styles,
+                id,
             }
-            RnNode::View { children, styles: Some(cite_style) }
         }
-        "code" | "tt" => {
-            let mut code_style = merged_style.unwrap_or_else(|| create_empty_style());
-            code_style.fontFamily = Some("monospace".to_string());
-            code_style.fontSize = Some(14.0);
-            
-            if children.len() == 1 {
-                if let RnNode::Text { content, .. } = &children[0] {
-                    return RnNode::Text { content: content.clone(), styles: Some(code_style) };
-                }
+        // 텍스트 서식 요소: 서식은 자식 Text에 상속됨
+        "strong" | "b" | "em" | "i" | "u" | "cite" | "code" | "tt" | "sup" | "sub" | "small" | "big" if !block => {
+            // 텍스트 하나만 있으면 Span 래핑 없이 텍스트만 반환 (두 id가 모두 있으면 둘 다 보존하도록 래핑)
+            if children.len() == 1
+                && let RnNode::Text { content, id: text_id, .. } = &children[0]
+                && (id.is_none() || text_id.is_none())
+            {
+                return RnNode::Text { content: content.clone(), styles: inline_text_styles(&resolved_style), id: id.or(text_id.clone()) };
             }
-            RnNode::View { children, styles: Some(code_style) }
-        }
-        "pre" => {
-            let mut pre_style = merged_style.unwrap_or_else(|| create_empty_style());
-            pre_style.fontFamily = Some("monospace".to_string());
-            pre_style.fontSize = Some(14.0);
-            pre_style.marginTop = Some(8.0);
-            pre_style.marginBottom = Some(8.0);
-            RnNode::View { children, styles: Some(pre_style) }
-        }
-        "sup" => {
-            let mut sup_style = merged_style.unwrap_or_else(|| create_empty_style());
-            sup_style.fontSize = Some(12.0);
             
-            if children.len() == 1 {
-                if let RnNode::Text { content, .. } = &children[0] {
-                    return RnNode::Text { content: content.clone(), styles: Some(sup_style) };
+            RnNode::Span { children, styles: computed_style, id }
+        }
+        "a" => {
+            match element.value().attr("href").and_then(|href| resolve_link(href, context)) {
+                Some(target) if is_note_reference(element, &target, context) => {
+                    RnNode::NoteRef { target, children, styles: computed_style, id }
                 }
+                Some(target) => RnNode::Link { target, children, styles: computed_style, id },
+                // href가 없는 앵커나 책 밖을 가리키는 링크는 일반 요소로 처리
+                None => container_node(block, children, computed_style, id),
             }
-            RnNode::View { children, styles: Some(sup_style) }
         }
-        "sub" => {
-            let mut sub_style = merged_style.unwrap_or_else(|| create_empty_style());
-            sub_style.fontSize = Some(12.0);
-            
-            if children.len() == 1 {
-                if let RnNode::Text { content, .. } = &children[0] {
-                    return RnNode::Text { content: content.clone(), styles: Some(sub_style) };
-                }
+        _ => container_node(block, children, computed_style, id),
+    }
+}
+
+/// 블록이면 View, 인라인이면 Span
+fn container_node(block: bool, children: Vec<RnNode>, styles: Option<RnStyles>, id: Option<String>) -> RnNode {
+    if block {
+        RnNode::View { children, styles, id }
+    } else {
+        RnNode::Span { children, styles, id }
+    }
+}
+
+/// 문단 안에서 텍스트와 함께 흐르는 노드인지 여부
+fn is_inline_node(node: &RnNode) -> bool {
+    matches!(
+        node,
+        RnNode::Text { .. } | RnNode::Span { .. } | RnNode::Link { .. } | RnNode::NoteRef { .. } | RnNode::Image { .. }
+    )
+}
+
+/// 문서에 있는 주석 요소 수집: 주석으로 표시된 요소와, 주석 참조가 가리키는 (다른 문서일 수 있는) 요소
+pub fn collect_note_ids(document: &Html, context: &ConvertContext) -> Vec<(usize, String)> {
+    let Some(&spine_index) = context.spine_paths.get(&percent_decode(context.path)) else {
+        return Vec::new();
+    };
+    let mut note_ids = Vec::new();
+    for element in document.root_element().descendants().filter_map(ElementRef::wrap) {
+        if note_kind(element).is_some()
+            && let Some(id) = element.value().id()
+        {
+            note_ids.push((spine_index, id.to_string()));
+        }
+        if element.value().name() == "a"
+            && is_noteref(element)
+            && let Some((target_index, Some(fragment))) = element.value().attr("href").and_then(|href| internal_target(href, context))
+        {
+            note_ids.push((target_index, fragment));
+        }
+    }
+    note_ids
+}
+
+/// 문서에서 주석으로 추출할 요소: 노드 ID → (종류, 주석 id)
+///
+/// 주석으로 표시된 요소와 주석 참조가 가리키는 요소입니다. 참조 대상이 인라인 `<a>`/`<span>`이면
+/// (EPUB 2에서 흔한 `<p><a id="fn1" href="#r1">1.</a> 주석 본문</p>`처럼 되돌아가기 링크에 id가 있는 경우)
+/// 가장 가까운 블록 조상을 주석으로 보고, 주석 id는 참조된 id를 그대로 씁니다.
+pub fn collect_note_elements(document: &Html, context: &ConvertContext) -> HashMap<NodeId, (NoteKind, String)> {
+    let spine_index = context.spine_paths.get(&percent_decode(context.path)).copied();
+    let mut note_elements = HashMap::new();
+    for element in document.root_element().descendants().filter_map(ElementRef::wrap) {
+        let Some(id) = element.value().id() else {
+            continue;
+        };
+        if let Some(kind) = note_kind(element) {
+            note_elements.insert(element.id(), (kind, id.to_string()));
+        } else if spine_index.is_some_and(|spine_index| context.note_ids.contains(&(spine_index, id.to_string()))) {
+            let note = match element.value().name() {
+                // 본문 전체나 주석 구역 전체를 하나의 주석으로 만들지는 않음
+                "a" | "span" => element.ancestors()
+                    .filter_map(ElementRef::wrap)
+                    .find(|ancestor| is_block_element(*ancestor))
+                    .filter(|block| block.value().name() != "body" && !is_note_section(*block))
+                    .unwrap_or(element),
+                _ => element,
+            };
+            note_elements.entry(note.id()).or_insert((NoteKind::Note, id.to_string()));
+        }
+    }
+    note_elements
+}
+
+/// 주석 요소의 (종류, 주석 id) (다른 주석 안에 있는 요소는 그 주석의 일부로 봄)
+fn note_of(element: ElementRef, context: &ConvertContext) -> Option<(NoteKind, String)> {
+    let note = context.note_elements.get(&element.id())?;
+    let nested = element.ancestors()
+        .filter_map(ElementRef::wrap)
+        .any(|ancestor| context.note_elements.contains_key(&ancestor.id()));
+    (!nested).then(|| note.clone())
+}
+
+/// 주석 참조로 표시되었거나 주석 요소를 가리키는 링크인지 여부
+fn is_note_reference(element: ElementRef, target: &LinkTarget, context: &ConvertContext) -> bool {
+    match target {
+        LinkTarget::Internal { spine_index, fragment: Some(fragment) } => {
+            is_noteref(element) || context.note_ids.contains(&(*spine_index, fragment.clone()))
+        }
+        _ => false,
+    }
+}
+
+/// 연속된 인라인 노드들을 블록의 텍스트 스타일을 가진 Span 하나로 묶음 (노드가 하나뿐이면 그대로 둠)
+fn group_inline_runs(children: Vec<RnNode>, text_style: &Option<RnStyles>) -> Vec<RnNode> {
+    let mut grouped = Vec::with_capacity(children.len());
+    let mut run = Vec::new();
+    let flush = |run: &mut Vec<RnNode>, grouped: &mut Vec<RnNode>| match run.len() {
+        0 => {}
+        1 => grouped.append(run),
+        _ => grouped.push(RnNode::Span { children: std::mem::take(run), styles: text_style.clone(), id: None }),
+    };
+    
+    for child in children {
+        if is_inline_node(&child) {
+            run.push(child);
+        } else {
+            flush(&mut run, &mut grouped);
+            grouped.push(child);
+        }
+    }
+    flush(&mut run, &mut grouped);
+    grouped
+}
+
+/// 본문에서 뺀 주석(`hide_notes`) 안을 가리키는 링크를 그 주석의 참조(`NoteRef`)로 바꿈
+///
+/// 흐름에서 빠진 주석 안의 id는 챕터의 `anchors`에 없으므로, 그대로 두면 링크가 가리킬 곳이 없습니다.
+/// 목차의 앵커는 `anchors`로 확인하므로 이런 id를 가리키면 챕터만 가리키게 됩니다.
+pub fn redirect_links_into_notes(chapters: &mut [ChapterStructure]) {
+    // (spine 인덱스, 주석 안의 id) → 주석 id
+    let mut note_ids = HashMap::new();
+    for chapter in chapters.iter() {
+        for note in &chapter.notes {
+            let mut anchors = collect_anchors(&note.content);
+            anchors.push(ChapterAnchor { id: note.id.clone(), node_path: Vec::new() });
+            for anchor in anchors {
+                note_ids.insert((chapter.spine_index, anchor.id), note.id.clone());
             }
-            RnNode::View { children, styles: Some(sub_style) }
         }
-        "small" => {
-            let mut small_style = merged_style.unwrap_or_else(|| create_empty_style());
-            small_style.fontSize = Some(12.0);
-            
-            if children.len() == 1 {
-                if let RnNode::Text { content, .. } = &children[0] {
-                    return RnNode::Text { content: content.clone(), styles: Some(small_style) };
-                }
+    }
+    if note_ids.is_empty() {
+        return;
+    }
+    for chapter in chapters {
+        redirect_note_links(&mut chapter.content, &note_ids);
+        for note in &mut chapter.notes {
+            redirect_note_links(&mut note.content, &note_ids);
+        }
+    }
+}
+
+fn redirect_note_links(node: &mut RnNode, note_ids: &HashMap<(usize, String), String>) {
+    match node {
+        RnNode::Link { target: LinkTarget::Internal { spine_index, fragment: Some(fragment) }, children, styles, id } => {
+            if let Some(note_id) = note_ids.get(&(*spine_index, fragment.clone())) {
+                let target = LinkTarget::Internal { spine_index: *spine_index, fragment: Some(note_id.clone()) };
+                let note_ref = RnNode::NoteRef { target, children: std::mem::take(children), styles: styles.take(), id: id.take() };
+                *node = note_ref;
             }
-            RnNode::View { children, styles: Some(small_style) }
         }
-        "big" => {
-            let mut big_style = merged_style.unwrap_or_else(|| create_empty_style());
-            big_style.fontSize = Some(20.0);
-            
-            if children.len() == 1 {
-                if let RnNode::Text { content, .. } = &children[0] {
-                    return RnNode::Text { content: content.clone(), styles: Some(big_style) };
-                }
+        RnNode::NoteRef { target: LinkTarget::Internal { spine_index, fragment: Some(fragment) }, .. } => {
+            if let Some(note_id) = note_ids.get(&(*spine_index, fragment.clone())) {
+                *fragment = note_id.clone();
             }
-            RnNode::View { children, styles: Some(big_style) }
         }
-        "center" => {
-            let mut center_style = merged_style.unwrap_or_else(|| create_empty_style());
-            center_style.textAlign = Some("center".to_string());
-            RnNode::View { children, styles: Some(center_style) }
+        _ => {}
+    }
+    for child in node.children_mut() {
+        redirect_note_links(child, note_ids);
+    }
+}
+
+/// 노드 트리에서 id가 있는 노드의 위치를 문서 순서대로 수집
+pub fn collect_anchors(node: &RnNode) -> Vec<ChapterAnchor> {
+    let mut anchors = Vec::new();
+    collect_anchors_into(node, &mut Vec::new(), &mut anchors);
+    anchors
+}
+
+fn collect_anchors_into(node: &RnNode, path: &mut Vec<usize>, anchors: &mut Vec<ChapterAnchor>) {
+    if let Some(id) = node.id() {
+        anchors.push(ChapterAnchor { id: id.to_string(), node_path: path.clone() });
+    }
+    for (index, child) in node.children().iter().enumerate() {
+        path.push(index);
+        collect_anchors_into(child, path, anchors);
+        path.pop();
+    }
+}
+
+/// 이미지 `src`를 `Image` 노드의 `source` 값과 manifest의 이미지 정보로 해석
+///
+/// 상대 경로는 현재 문서 기준 아카이브 경로로 바꿔 manifest의 이미지와 비교하며,
+/// data URI와 외부 URL은 그대로 사용합니다. manifest에 없으면 None을 반환합니다.
+fn resolve_image<'a>(src: &str, context: &ConvertContext<'a>) -> Option<(String, Option<&'a ImageAsset>)> {
+    let src = src.trim();
+    if url_scheme(src).is_some() {
+        return Some((src.to_string(), None));
+    }
+    let path = percent_decode(&resolve_relative_path(context.path, src));
+    let id = context.image_paths.get(&path)?;
+    Some((context.images.get(id)?.clone(), context.assets.get(id)))
+}
+
+/// 이미지 요소의 원본 참조 (`<img src>` 또는 SVG `<image href>`/`<image xlink:href>`)
+fn image_src<'a>(element: ElementRef<'a>) -> Option<&'a str> {
+    let value = element.value();
+    match value.name() {
+        "img" => value.attr("src"),
+        // SVG의 xlink:href는 xlink 네임스페이스의 href 속성으로 파싱됨
+        _ => value.attrs().find(|(name, _)| *name == "href").map(|(_, href)| href),
+    }
+}
+
+/// 이미지 노드의 (너비, 높이, 비율)
+///
+/// HTML `width`/`height` 속성을 우선하고, 하나만 있으면 나머지는 고유 비율로 계산합니다.
+fn image_size(element: scraper::ElementRef, asset: Option<&ImageAsset>) -> (Option<f32>, Option<f32>, Option<f32>) {
+    // 속성 값은 픽셀 (퍼센트는 무시)
+    let attribute = |name: &str| {
+        element.value().attr(name)
+            .and_then(|value| value.trim().trim_end_matches("px").parse::<f32>().ok())
+            .filter(|value| *value > 0.0)
+    };
+    let ratio = asset.and_then(|asset| asset.aspect_ratio);
+
+    match (attribute("width"), attribute("height")) {
+        (Some(width), Some(height)) => (Some(width), Some(height), Some(width / height)),
+        (Some(width), None) => (Some(width), ratio.map(|ratio| width / ratio), ratio),
+        (None, Some(height)) => (ratio.map(|ratio| height * ratio), Some(height), ratio),
+        (None, None) => (asset.and_then(|asset| asset.width), asset.and_then(|asset| asset.height), ratio),
+    }
+}
+
+/// `href`를 링크 대상으로 해석
+///
+/// 상대 경로는 현재 문서 기준으로 해석해 spine 인덱스로 바꾸며,
+/// spine에 없는 문서를 가리키면 None을 반환합니다.
+fn resolve_link(href: &str, context: &ConvertContext) -> Option<LinkTarget> {
+    let href = href.trim();
+    if href.is_empty() {
+        return None;
+    }
+
+    if let Some(scheme) = url_scheme(href) {
+        return Some(if scheme.eq_ignore_ascii_case("mailto") {
+            let address = href[scheme.len() + 1..].split('?').next().unwrap_or_default();
+            LinkTarget::Mailto { url: href.to_string(), address: address.to_string() }
+        } else {
+            LinkTarget::External { url: href.to_string() }
+        });
+    }
+
+    match internal_target(href, context) {
+        Some((spine_index, fragment)) => Some(LinkTarget::Internal { spine_index, fragment }),
+        None => {
+            log!("   ⚠️  Link target not in spine: {}", href_path(href, context));
+            None
+        }
+    }
+}
+
+/// 상대 `href`가 가리키는 문서의 아카이브 경로 (`#id`만 있으면 현재 문서)
+fn href_path(href: &str, context: &ConvertContext) -> String {
+    match href.split('#').next().unwrap_or_default() {
+        "" => context.path.to_string(),
+        path => resolve_relative_path(context.path, path),
+    }
+}
+
+/// 상대 `href`가 가리키는 spine 인덱스와 프래그먼트 (spine에 없으면 None)
+fn internal_target(href: &str, context: &ConvertContext) -> Option<(usize, Option<String>)> {
+    let href = href.trim();
+    let fragment = href.split_once('#').map(|(_, fragment)| fragment.to_string()).filter(|f| !f.is_empty());
+    let spine_index = *context.spine_paths.get(&percent_decode(&href_path(href, context)))?;
+    Some((spine_index, fragment))
+}
+
+/// 문서에 적용되는 스타일시트 구성
+///
+/// 기본 EPUB 스타일 위에 `<link rel="stylesheet">`와 `<style>`을 문서 순서대로 쌓습니다.
+/// `stylesheets`는 아카이브 경로별로 미리 파싱된 CSS 파일이며, `path`는 이 문서의 아카이브 경로입니다.
+pub fn document_stylesheet(
+    document: &Html,
+    path: &str,
+    stylesheets: &HashMap<String, Stylesheet>,
+    options: &ConversionOptions,
+) -> Stylesheet {
+    let mut stylesheet = Stylesheet::user_agent();
+    let style_selector = Selector::parse("link, style").unwrap();
+
+    for element in document.select(&style_selector) {
+        if let Some(media) = element.value().attr("media")
+            && !media_query_matches(media, options)
+        {
+            continue;
         }
-        _ => {
-            RnNode::View { children, styles: merged_style }
+
+        match element.value().name() {
+            "link" => {
+                let rel = element.value().attr("rel").unwrap_or_default().to_ascii_lowercase();
+                let rel: Vec<&str> = rel.split_whitespace().collect();
+                // 대체 스타일시트는 기본으로 적용되지 않음
+                if !rel.contains(&"stylesheet") || rel.contains(&"alternate") {
+                    continue;
+                }
+                let Some(href) = element.value().attr("href") else {
+                    continue;
+                };
+                // href는 퍼센트 인코딩되어 있을 수 있음 (스타일시트는 디코딩된 경로로 보관됨)
+                let target = percent_decode(&resolve_relative_path(path, href));
+                match stylesheets.get(&target) {
+                    Some(linked) => stylesheet.extend(linked.clone()),
+                    None => {
+                        log!("   ⚠️  Linked stylesheet not in manifest: {}", target);
+                    }
+                }
+            }
+            "style" => {
+                if element.value().attr("type").is_some_and(|t| !t.trim().eq_ignore_ascii_case("text/css")) {
+                    continue;
+                }
+                let css: String = element.text().collect();
+                stylesheet.extend(Stylesheet::parse_with(&css, path, options, &mut |url| {
+                    stylesheets.get(&percent_decode(&resolve_relative_path(path, url))).cloned()
+                }));
+            }
+            _ => {}
         }
     }
+
+    stylesheet
 }
 
 /// HTML에서 제목 추출
@@ -345,46 +651,9 @@ pub fn extract_title_from_html(html: &str) -> Option<String> {
     }
 }
 
-/// 인라인 스타일 추출
-fn extract_inline_styles(element: scraper::ElementRef) -> Option<RnStyles> {
-    if let Some(style_attr) = element.value().attr("style") {
-        Some(parse_css_declarations(style_attr))
-    } else {
-        None
-    }
-}
-
-/// CSS 클래스 스타일 추출
-fn extract_class_styles(element: scraper::ElementRef, styles: &HashMap<String, RnStyles>) -> Option<RnStyles> {
-    if let Some(class_attr) = element.value().attr("class") {
-        for class_name in class_attr.split_whitespace() {
-            // 1. 정확한 클래스명으로 먼저 찾기
-            if let Some(style) = styles.get(class_name) {
-                return Some(style.clone());
-            }
-            
-            // 2. 복합 선택자에서 이 클래스를 포함한 스타일 찾기
-            for (key, style) in styles.iter() {
-                // "toc_toc-title" → "toc-title"로 매칭
-                // "titlepage_copyright" → "copyright"로 매칭
-                if key.contains('_') && key.ends_with(class_name) {
-                    return Some(style.clone());
-                }
-                // "titlepage_copyright,_titlepage_legalnotice_p" 같은 경우도 처리
-                if key.contains(class_name) && (key.contains('_') || key.contains(',')) {
-                    return Some(style.clone());
-                }
-            }
-            
-            // 3. 일반적인 클래스명에 대한 기본 스타일 제공
-            if let Some(default_style) = get_default_class_style(class_name) {
-                return Some(default_style);
-            }
-            
-            log!("     ❌ No style found: {}", class_name);
-        }
-    }
-    None
+/// 요소의 클래스명 중 기본 스타일이 있는 첫 번째 클래스의 스타일
+fn default_class_style(element: scraper::ElementRef) -> Option<RnStyles> {
+    element.value().classes().find_map(get_default_class_style)
 }
 
 /// 일반적인 클래스명에 대한 기본 스타일 제공
@@ -424,16 +693,16 @@ fn get_default_class_style(class_name: &str) -> Option<RnStyles> {
         // EPUB 책 구조 관련
         "book" | "chapter" => {
             let mut style = create_empty_style();
-            style.marginTop = Some(16.0);
-            style.marginBottom = Some(16.0);
+            style.marginTop = Some(RnDimension::Points(16.0));
+            style.marginBottom = Some(RnDimension::Points(16.0));
             Some(style)
         }
         "dedication" => {
             let mut style = create_empty_style();
             style.fontStyle = Some("italic".to_string());
             style.textAlign = Some("center".to_string());
-            style.marginTop = Some(32.0);
-            style.marginBottom = Some(32.0);
+            style.marginTop = Some(RnDimension::Points(32.0));
+            style.marginBottom = Some(RnDimension::Points(32.0));
             Some(style)
         }
         // 링크 스타일
@@ -452,25 +721,25 @@ fn get_default_class_style(class_name: &str) -> Option<RnStyles> {
         "quote" | "quotation" => {
             let mut style = create_empty_style();
             style.fontStyle = Some("italic".to_string());
-            style.marginLeft = Some(16.0);
-            style.marginRight = Some(16.0);
+            style.marginLeft = Some(RnDimension::Points(16.0));
+            style.marginRight = Some(RnDimension::Points(16.0));
             Some(style)
         }
         "note" | "footnote" => {
             let mut style = create_empty_style();
             style.fontSize = Some(12.0);
-            style.marginTop = Some(8.0);
-            style.marginBottom = Some(8.0);
+            style.marginTop = Some(RnDimension::Points(8.0));
+            style.marginBottom = Some(RnDimension::Points(8.0));
             Some(style)
         }
         "sidebar" => {
             let mut style = create_empty_style();
-            style.marginLeft = Some(16.0);
-            style.marginRight = Some(16.0);
-            style.paddingTop = Some(8.0);
-            style.paddingBottom = Some(8.0);
-            style.paddingLeft = Some(8.0);
-            style.paddingRight = Some(8.0);
+            style.marginLeft = Some(RnDimension::Points(16.0));
+            style.marginRight = Some(RnDimension::Points(16.0));
+            style.paddingTop = Some(RnDimension::Points(8.0));
+            style.paddingBottom = Some(RnDimension::Points(8.0));
+            style.paddingLeft = Some(RnDimension::Points(8.0));
+            style.paddingRight = Some(RnDimension::Points(8.0));
             Some(style)
         }
         _ => None
@@ -482,56 +751,29 @@ fn create_empty_style() -> RnStyles {
     RnStyles::default()
 }
 
-
-/// 자식 노드들의 텍스트에 스타일 적용
-fn apply_text_style_to_children(children: Vec<RnNode>, text_style: &RnStyles) -> Vec<RnNode> {
-    children.into_iter().map(|child| {
-        match child {
-            RnNode::Text { content, styles } => {
-                let mut combined_style = text_style.clone();
-                if let Some(existing_styles) = styles {
-                    // 기존 스타일을 우선으로 병합
-                    if existing_styles.fontSize.is_some() { combined_style.fontSize = existing_styles.fontSize; }
-                    if existing_styles.fontWeight.is_some() { combined_style.fontWeight = existing_styles.fontWeight; }
-                    if existing_styles.fontFamily.is_some() { combined_style.fontFamily = existing_styles.fontFamily; }
-                    if existing_styles.color.is_some() { combined_style.color = existing_styles.color; }
-                    if existing_styles.fontStyle.is_some() { combined_style.fontStyle = existing_styles.fontStyle; }
-                    if existing_styles.textDecorationLine.is_some() { combined_style.textDecorationLine = existing_styles.textDecorationLine; }
-                    if existing_styles.lineHeight.is_some() { combined_style.lineHeight = existing_styles.lineHeight; }
-                }
-                RnNode::Text { content, styles: Some(combined_style) }
-            }
-            RnNode::View { children, styles } => {
-                let styled_children = apply_text_style_to_children(children, text_style);
-                RnNode::View { children: styled_children, styles }
-            }
-            other => other
-        }
-    }).collect()
-}
-
 /// 레이아웃 스타일만 추출 (텍스트 스타일 제외)
 fn extract_layout_styles(style: &RnStyles) -> Option<RnStyles> {
-    let mut layout_style = RnStyles::default();
-    
     // 레이아웃 관련 속성만 복사
-    layout_style.backgroundColor = style.backgroundColor.clone();
-    layout_style.textAlign = style.textAlign.clone();
-    layout_style.marginTop = style.marginTop;
-    layout_style.marginBottom = style.marginBottom;
-    layout_style.marginLeft = style.marginLeft;
-    layout_style.marginRight = style.marginRight;
-    layout_style.paddingTop = style.paddingTop;
-    layout_style.paddingBottom = style.paddingBottom;
-    layout_style.paddingLeft = style.paddingLeft;
-    layout_style.paddingRight = style.paddingRight;
-    layout_style.width = style.width;
-    layout_style.height = style.height;
-    layout_style.position = style.position.clone();
-    layout_style.display = style.display.clone();
-    layout_style.flexDirection = style.flexDirection.clone();
-    layout_style.justifyContent = style.justifyContent.clone();
-    layout_style.alignItems = style.alignItems.clone();
+    let layout_style = RnStyles {
+        backgroundColor: style.backgroundColor.clone(),
+        textAlign: style.textAlign.clone(),
+        marginTop: style.marginTop,
+        marginBottom: style.marginBottom,
+        marginLeft: style.marginLeft,
+        marginRight: style.marginRight,
+        paddingTop: style.paddingTop,
+        paddingBottom: style.paddingBottom,
+        paddingLeft: style.paddingLeft,
+        paddingRight: style.paddingRight,
+        width: style.width,
+        height: style.height,
+        position: style.position.clone(),
+        display: style.display.clone(),
+        flexDirection: style.flexDirection.clone(),
+        justifyContent: style.justifyContent.clone(),
+        alignItems: style.alignItems.clone(),
+        ..Default::default()
+    };
     
     // 레이아웃 스타일이 하나라도 있으면 반환
     if layout_style.backgroundColor.is_some() || layout_style.textAlign.is_some() ||
@@ -543,4 +785,196 @@ fn extract_layout_styles(style: &RnStyles) -> Option<RnStyles> {
     } else {
         None
     }
-} 
\ No newline at end of file
+} 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `OEBPS/Text/c.xhtml`(spine 0)과 이미지 `OEBPS/Images/fig 1.png`(200×100)로 된 책에서
+    /// body를 변환해 (본문, 주석)을 반환
+    fn convert(body: &str, hide_notes: bool) -> (RnNode, Vec<Note>) {
+        let document = Html::parse_document(&format!("<html><body>{body}</body></html>"));
+        let stylesheet = Stylesheet::user_agent();
+        let options = ConversionOptions { hide_notes, ..Default::default() };
+        let asset = ImageAsset {
+            path: "OEBPS/Images/fig 1.png".to_string(),
+            mime_type: "image/png".to_string(),
+            size: 0,
+            width: Some(200.0),
+            height: Some(100.0),
+            aspect_ratio: Some(2.0),
+            file: None,
+        };
+        let images = HashMap::from([("fig".to_string(), "fig-source".to_string())]);
+        let image_paths = HashMap::from([(asset.path.clone(), "fig".to_string())]);
+        let assets = HashMap::from([("fig".to_string(), asset)]);
+        let spine_paths = HashMap::from([("OEBPS/Text/c.xhtml".to_string(), 0)]);
+        let notes = RefCell::new(Vec::new());
+        let context = ConvertContext {
+            stylesheet: &stylesheet,
+            images: &images,
+            image_paths: &image_paths,
+            assets: &assets,
+            fonts: &[],
+            options: &options,
+            path: "OEBPS/Text/c.xhtml",
+            spine_paths: &spine_paths,
+            unresolved: &RefCell::new(Vec::new()),
+            line_state: &Cell::new(LineState::LineStart),
+            note_ids: &HashSet::new(),
+            note_elements: &HashMap::new(),
+            notes: &notes,
+            list_ordinal: None,
+        };
+        let note_ids: HashSet<_> = collect_note_ids(&document, &context).into_iter().collect();
+        let note_elements = collect_note_elements(&document, &ConvertContext { note_ids: &note_ids, ..context });
+        let content = convert_document_to_rn_nodes(
+            &document,
+            &ConvertContext { note_ids: &note_ids, note_elements: &note_elements, ..context },
+        );
+        (content, notes.into_inner())
+    }
+
+    fn text(node: &RnNode) -> String {
+        match node {
+            RnNode::Text { content, .. } => content.clone(),
+            _ => node.children().iter().map(text).collect(),
+        }
+    }
+
+    fn find<'a>(node: &'a RnNode, matches: &dyn Fn(&RnNode) -> bool) -> Option<&'a RnNode> {
+        if matches(node) {
+            return Some(node);
+        }
+        node.children().iter().find_map(|child| find(child, matches))
+    }
+
+    #[test]
+    fn resolves_svg_image_references() {
+        let body = r#"<svg xmlns:xlink="http://www.w3.org/1999/xlink" viewBox="0 0 200 100">
+            <image width="100" xlink:href="../Images/fig%201.png"/></svg>
+            <svg><image href="../Images/fig%201.png"/></svg>"#;
+        let (content, _) = convert(body, false);
+        let mut images = Vec::new();
+        let mut stack = vec![&content];
+        while let Some(node) = stack.pop() {
+            if let RnNode::Image { source, width, height, .. } = node {
+                images.push((source.as_str(), *width, *height));
+            }
+            stack.extend(node.children().iter().rev());
+        }
+        assert_eq!(images, [("fig-source", Some(100.0), Some(50.0)), ("fig-source", Some(200.0), Some(100.0))]);
+    }
+
+    #[test]
+    fn list_type_attribute_sets_markers() {
+        let (content, _) = convert(r#"<ol type="i" start="3"><li>a</li><li>b</li></ol><ol><li type="A">c</li></ol>"#, false);
+        let mut markers = Vec::new();
+        let mut stack = vec![&content];
+        while let Some(node) = stack.pop() {
+            if let RnNode::ListItem { marker, .. } = node {
+                markers.push(marker.as_str());
+            }
+            stack.extend(node.children().iter().rev());
+        }
+        assert_eq!(markers, ["iii.", "iv.", "A."]);
+    }
+
+    #[test]
+    fn row_groups_keep_id_and_background() {
+        let body = r#"<table><tbody id="g" style="background-color: #eee"><tr><td>a</td></tr>
+            <tr style="background-color: #fff"><td>b</td></tr></tbody></table>"#;
+        let (content, _) = convert(body, false);
+        let table = find(&content, &|node| matches!(node, RnNode::Table { .. })).expect("Table");
+        let rows: Vec<_> = table.children().iter()
+            .map(|row| match row {
+                RnNode::TableRow { styles, id, .. } => {
+                    (id.as_deref(), styles.as_ref().and_then(|style| style.backgroundColor.as_deref()))
+                }
+                _ => panic!("표의 자식은 행이어야 함: {:?}", row),
+            })
+            .collect();
+        assert_eq!(rows, [(Some("g"), Some("#eeeeee")), (None, Some("#ffffff"))]);
+    }
+
+    const MARKED: &str = r##"<p>Body<a epub:type="noteref" href="#n1">1</a></p>
+        <aside epub:type="footnote" id="n1"><p>Note text</p></aside>"##;
+
+    #[test]
+    fn extracts_marked_notes_and_keeps_them_in_flow() {
+        let (content, notes) = convert(MARKED, false);
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].id, "n1");
+        assert_eq!(notes[0].kind, NoteKind::Footnote);
+        assert_eq!(text(&notes[0].content), "Note text");
+        assert!(text(&content).contains("Note text"));
+    }
+
+    #[test]
+    fn hidden_notes_are_removed_from_flow() {
+        let (content, notes) = convert(MARKED, true);
+        assert_eq!(notes.len(), 1);
+        assert!(!text(&content).contains("Note text"));
+    }
+
+    #[test]
+    fn noteref_links_become_note_refs() {
+        let (content, _) = convert(MARKED, false);
+        let note_ref = find(&content, &|node| matches!(node, RnNode::NoteRef { .. })).expect("NoteRef");
+        let RnNode::NoteRef { target, .. } = note_ref else { unreachable!() };
+        assert_eq!(target, &LinkTarget::Internal { spine_index: 0, fragment: Some("n1".to_string()) });
+    }
+
+    #[test]
+    fn referenced_inline_anchor_extracts_its_block() {
+        // EPUB 2: 되돌아가기 링크에 id가 있고 주석 본문은 같은 문단에 있음
+        let body = r##"<p>Body<a class="noteref" id="r1" href="#fn1">1</a></p>
+            <p><a id="fn1" href="#r1">1.</a> Note body</p>"##;
+        let (content, notes) = convert(body, true);
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].id, "fn1");
+        assert_eq!(notes[0].kind, NoteKind::Note);
+        assert_eq!(text(&notes[0].content), "1. Note body");
+        assert!(!text(&content).contains("Note body"));
+    }
+
+    #[test]
+    fn links_into_hidden_notes_are_redirected() {
+        let link = |fragment: &str| RnNode::Link {
+            target: LinkTarget::Internal { spine_index: 0, fragment: Some(fragment.to_string()) },
+            children: vec![RnNode::Text { content: "see".to_string(), styles: None, id: None }],
+            styles: None,
+            id: None,
+        };
+        let note = Note {
+            id: "n1".to_string(),
+            kind: NoteKind::Footnote,
+            content: RnNode::View {
+                children: vec![RnNode::Text { content: "Note text".to_string(), styles: None, id: Some("n1-p".to_string()) }],
+                styles: None,
+                id: Some("n1".to_string()),
+            },
+        };
+        let chapter = |spine_index: usize, content: RnNode, notes: Vec<Note>| ChapterStructure {
+            spine_index,
+            idref: format!("c{spine_index}"),
+            title: None,
+            content,
+            anchors: vec![],
+            unresolved_references: vec![],
+            notes,
+        };
+        let mut chapters = vec![
+            chapter(0, link("other"), vec![note]),
+            // 주석 안의 요소를 가리키는 다른 챕터의 링크
+            chapter(1, link("n1-p"), vec![]),
+        ];
+        redirect_links_into_notes(&mut chapters);
+        assert!(matches!(chapters[0].content, RnNode::Link { .. }));
+        let RnNode::NoteRef { target, children, .. } = &chapters[1].content else {
+            panic!("링크가 NoteRef로 바뀌어야 함: {:?}", chapters[1].content);
+        };
+        assert_eq!(target, &LinkTarget::Internal { spine_index: 0, fragment: Some("n1".to_string()) });
+        assert_eq!(children.len(), 1);
+    }
+}
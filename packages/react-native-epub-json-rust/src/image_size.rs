@@ -0,0 +1,205 @@
+use crate::css_parser::{resolve_length, LengthContext};
+use xml::reader::{EventReader, XmlEvent};
+
+/// 이미지 데이터의 헤더에서 고유 크기 (너비, 높이) 읽기
+///
+/// PNG, JPEG, GIF, WebP는 시그니처로 형식을 판별하고, 그 외에는 SVG로 시도합니다.
+/// 크기를 알 수 없으면 None을 반환합니다.
+pub fn image_dimensions(data: &[u8]) -> Option<(f32, f32)> {
+    let (width, height) = match data {
+        [0x89, b'P', b'N', b'G', ..] => png_dimensions(data)?,
+        [0xFF, 0xD8, ..] => jpeg_dimensions(data)?,
+        [b'G', b'I', b'F', b'8', ..] => gif_dimensions(data)?,
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => webp_dimensions(data)?,
+        _ => return svg_dimensions(std::str::from_utf8(data).ok()?),
+    };
+    (width > 0 && height > 0).then_some((width as f32, height as f32))
+}
+
+fn u16_be(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u16::from_be_bytes(data.get(offset..offset + 2)?.try_into().ok()?) as u32)
+}
+
+fn u16_le(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u16::from_le_bytes(data.get(offset..offset + 2)?.try_into().ok()?) as u32)
+}
+
+fn u24_le(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 3)?;
+    Some(bytes[0] as u32 | (bytes[1] as u32) << 8 | (bytes[2] as u32) << 16)
+}
+
+/// PNG: IHDR 청크의 너비/높이 (빅 엔디언 32비트)
+fn png_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    if data.get(12..16)? != b"IHDR" {
+        return None;
+    }
+    let width = u32::from_be_bytes(data.get(16..20)?.try_into().ok()?);
+    let height = u32::from_be_bytes(data.get(20..24)?.try_into().ok()?);
+    Some((width, height))
+}
+
+/// GIF: 논리 화면 크기 (리틀 엔디언 16비트)
+fn gif_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    Some((u16_le(data, 6)?, u16_le(data, 8)?))
+}
+
+/// JPEG: 첫 SOF 세그먼트의 크기
+fn jpeg_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    let mut offset = 2;
+    loop {
+        // 마커 앞의 채움 바이트(0xFF) 건너뛰기
+        while *data.get(offset)? == 0xFF && *data.get(offset + 1)? == 0xFF {
+            offset += 1;
+        }
+        if *data.get(offset)? != 0xFF {
+            return None;
+        }
+        let marker = *data.get(offset + 1)?;
+        offset += 2;
+
+        match marker {
+            // 길이가 없는 마커 (RST, SOI, TEM)
+            0xD0..=0xD8 | 0x01 => continue,
+            0xD9 | 0xDA => return None,
+            // SOF0~SOF15 (DHT, JPG, DAC 제외): 길이(2) 정밀도(1) 높이(2) 너비(2)
+            0xC0..=0xCF if !matches!(marker, 0xC4 | 0xC8 | 0xCC) => {
+                return Some((u16_be(data, offset + 5)?, u16_be(data, offset + 3)?));
+            }
+            _ => offset += u16_be(data, offset)? as usize,
+        }
+    }
+}
+
+/// WebP: 손실(`VP8 `), 무손실(`VP8L`), 확장(`VP8X`) 형식의 크기
+fn webp_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    match data.get(12..16)? {
+        b"VP8 " => Some((u16_le(data, 26)? & 0x3FFF, u16_le(data, 28)? & 0x3FFF)),
+        b"VP8L" => {
+            let bits = u32::from_le_bytes(data.get(21..25)?.try_into().ok()?);
+            Some(((bits & 0x3FFF) + 1, ((bits >> 14) & 0x3FFF) + 1))
+        }
+        b"VP8X" => Some((u24_le(data, 24)? + 1, u24_le(data, 27)? + 1)),
+        _ => None,
+    }
+}
+
+/// SVG: 루트 `<svg>`의 `width`/`height` (퍼센트 제외), 없으면 `viewBox` 비율로 보완
+///
+/// 문서 전체를 파싱하지 않고 첫 시작 태그까지만 읽으며, 그 전에 XML 오류가 나면 크기를 알 수 없는 것으로 봅니다.
+fn svg_dimensions(svg: &str) -> Option<(f32, f32)> {
+    let attributes = EventReader::from_str(svg).into_iter().find_map(|event| match event {
+        Ok(XmlEvent::StartElement { name, attributes, .. }) => Some((name.local_name == "svg").then_some(attributes)),
+        Ok(_) => None,
+        Err(_) => Some(None),
+    })??;
+    let attr = |name: &str| attributes.iter()
+        .find(|attribute| attribute.name.local_name == name)
+        .map(|attribute| attribute.value.as_str());
+    let length = |name: &str| {
+        attr(name)
+            .filter(|value| !value.trim().ends_with('%'))
+            .and_then(|value| resolve_length(value, &LengthContext::default()))
+            .filter(|value| *value > 0.0)
+    };
+    let view_box = attr("viewBox").and_then(|view_box| {
+        let values: Vec<f32> = view_box.split([' ', ',']).filter_map(|value| value.parse().ok()).collect();
+        match values.as_slice() {
+            [_, _, width, height] if *width > 0.0 && *height > 0.0 => Some((*width, *height)),
+            _ => None,
+        }
+    });
+
+    match (length("width"), length("height"), view_box) {
+        (Some(width), Some(height), _) => Some((width, height)),
+        (Some(width), None, Some((box_width, box_height))) => Some((width, width * box_height / box_width)),
+        (None, Some(height), Some((box_width, box_height))) => Some((height * box_width / box_height, height)),
+        (None, None, view_box) => view_box,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn webp(chunk: &[u8; 4], payload: &[u8]) -> Vec<u8> {
+        let mut data = b"RIFF\0\0\0\0WEBP".to_vec();
+        data.extend_from_slice(chunk);
+        data.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        data.extend_from_slice(payload);
+        data
+    }
+
+    #[test]
+    fn reads_png_size() {
+        let mut data = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        data.extend_from_slice(&640u32.to_be_bytes());
+        data.extend_from_slice(&480u32.to_be_bytes());
+        assert_eq!(image_dimensions(&data), Some((640.0, 480.0)));
+    }
+
+    #[test]
+    fn reads_gif_size() {
+        let data = b"GIF89a\x2c\x01\xc8\x00\0\0\0";
+        assert_eq!(image_dimensions(data), Some((300.0, 200.0)));
+    }
+
+    #[test]
+    fn reads_jpeg_size_after_other_segments() {
+        let mut data = vec![0xFF, 0xD8];
+        // APP0 (JFIF), 채움 바이트, SOF0
+        data.extend_from_slice(&[0xFF, 0xE0, 0x00, 0x10]);
+        data.extend_from_slice(b"JFIF\0\x01\x01\0\0\x01\0\x01\0\0");
+        data.extend_from_slice(&[0xFF, 0xFF, 0xC0, 0x00, 0x11, 0x08, 0x01, 0xE0, 0x02, 0x80]);
+        assert_eq!(image_dimensions(&data), Some((640.0, 480.0)));
+    }
+
+    #[test]
+    fn stops_jpeg_scan_at_start_of_scan() {
+        let data = [0xFF, 0xD8, 0xFF, 0xDA, 0x00, 0x08];
+        assert_eq!(image_dimensions(&data), None);
+    }
+
+    #[test]
+    fn reads_webp_sizes() {
+        // 손실: 프레임 태그(3) 시작 코드(3) 너비(2) 높이(2)
+        let lossy = webp(b"VP8 ", &[0, 0, 0, 0x9D, 0x01, 0x2A, 0x90, 0x01, 0x2C, 0x01]);
+        assert_eq!(image_dimensions(&lossy), Some((400.0, 300.0)));
+
+        // 무손실: 서명(1) 뒤 14비트씩 너비 - 1, 높이 - 1
+        let bits: u32 = 99 | (49 << 14);
+        let mut lossless = vec![0x2F];
+        lossless.extend_from_slice(&bits.to_le_bytes());
+        assert_eq!(image_dimensions(&webp(b"VP8L", &lossless)), Some((100.0, 50.0)));
+
+        // 확장: 플래그(4) 뒤 24비트씩 너비 - 1, 높이 - 1
+        let extended = webp(b"VP8X", &[0, 0, 0, 0, 0xFF, 0x0F, 0x00, 0x37, 0x04, 0x00]);
+        assert_eq!(image_dimensions(&extended), Some((4096.0, 1080.0)));
+    }
+
+    #[test]
+    fn reads_svg_size_from_attributes_or_view_box() {
+        let svg = br#"<?xml version="1.0"?><svg xmlns="http://www.w3.org/2000/svg" width="120" height="60pt"/>"#;
+        assert_eq!(image_dimensions(svg), Some((120.0, 80.0)));
+        let svg = br#"<svg width="100%" viewBox="0 0 300 150"><rect/></svg>"#;
+        assert_eq!(image_dimensions(svg), Some((300.0, 150.0)));
+    }
+
+    #[test]
+    fn reads_svg_size_despite_doctype_entities() {
+        let svg = br#"<?xml version="1.0"?>
+<!DOCTYPE svg PUBLIC "-//W3C//DTD SVG 1.1//EN" "http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd" [
+  <!ENTITY ns_svg "http://www.w3.org/2000/svg">
+]>
+<svg xmlns="&ns_svg;" width="64" height="32"><text>&undefined;</text></svg>"#;
+        assert_eq!(image_dimensions(svg), Some((64.0, 32.0)));
+    }
+
+    #[test]
+    fn returns_none_for_unknown_data() {
+        assert_eq!(image_dimensions(b"not an image"), None);
+        assert_eq!(image_dimensions(b"<html><body/></html>"), None);
+        assert_eq!(image_dimensions(b"\x89PNG\r\n\x1a\n"), None);
+    }
+}
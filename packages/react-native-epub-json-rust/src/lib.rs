@@ -12,25 +12,48 @@
 //! 
 //! ## 사용법
 //! 
-//! ```rust
-//! use epub_test::epub_extractor::extract_complete_epub_info;
+//! ```rust,no_run
+//! use react_native_epub_json::extract_complete_epub_info;
 //! 
+//! # fn main() -> Result<(), react_native_epub_json::EpubError> {
 //! let epub_info = extract_complete_epub_info("book.epub")?;
 //! let json = serde_json::to_string_pretty(&epub_info)?;
+//! # Ok(())
+//! # }
 //! ```
 
 pub mod types;
+pub mod error;
 pub mod css_parser;
+pub mod color;
+pub mod media;
+pub mod fonts;
+pub mod image_size;
+pub mod lists;
+pub mod tables;
+pub mod notes;
+pub mod whitespace;
+pub mod cascade;
 pub mod html_parser;
 pub mod utils;
+pub mod opf_parser;
+pub mod nav_parser;
+pub mod metadata;
+pub mod cover;
 pub mod epub_extractor;
 
 // 주요 타입들 재밷출
 pub use types::*;
-pub use epub_extractor::{extract_complete_epub_info, extract_complete_epub_info_from_bytes};
+pub use error::EpubError;
+pub use epub_extractor::{
+    extract_complete_epub_info, extract_complete_epub_info_from_bytes,
+    extract_complete_epub_info_with_options, extract_complete_epub_info_from_bytes_with_options,
+    extract_cover_image, extract_cover_image_from_bytes,
+    extract_epub_summary, extract_epub_summary_from_bytes,
+};
 
 /// CLI와 WASM에서 공통으로 사용할 변환 함수
-pub fn convert_epub_to_json(epub_path: &str, output_path: &str) -> Result<CompleteEpubInfo, Box<dyn std::error::Error>> {
+pub fn convert_epub_to_json(epub_path: &str, output_path: &str) -> Result<CompleteEpubInfo, EpubError> {
     let epub_info = extract_complete_epub_info(epub_path)?;
     let json = serde_json::to_string_pretty(&epub_info)?;
     std::fs::write(output_path, json)?;
@@ -52,6 +75,15 @@ pub fn main() {
     console_error_panic_hook::set_once();
 }
 
+/// 오류를 `code`, `message` 필드를 가진 JS `Error` 객체로 변환
+impl From<EpubError> for JsValue {
+    fn from(error: EpubError) -> Self {
+        let js_error = js_sys::Error::new(&error.to_string());
+        let _ = js_sys::Reflect::set(&js_error, &JsValue::from_str("code"), &JsValue::from_str(error.code()));
+        js_error.into()
+    }
+}
+
 /// Node.js에서 사용할 EPUB → JSON 변환 함수
 /// 
 /// # JavaScript 사용법
@@ -67,13 +99,12 @@ pub fn epub_to_json(epub_path: &str, output_dir: &str) -> Result<JsValue, JsValu
     // Node.js 환경에서는 정상 작동할 수 있음
     
     // 3. EPUB 추출 및 변환
-    let epub_info = extract_complete_epub_info(epub_path)
-        .map_err(|e| JsValue::from_str(&format!("EPUB 파일 처리 실패: {}", e)))?;
+    let epub_info = extract_complete_epub_info(epub_path)?;
     
     // 4. JSON 파일 저장 시도
     let output_path = format!("{}/book.json", output_dir.trim_end_matches('/'));
     let json = serde_json::to_string_pretty(&epub_info)
-        .map_err(|e| JsValue::from_str(&format!("JSON 직렬화 실패: {}", e)))?;
+        .map_err(EpubError::from)?;
     
     // 출력 디렉토리 생성 시도 (실패해도 계속 진행)
     let _ = std::fs::create_dir_all(output_dir);
@@ -83,7 +114,7 @@ pub fn epub_to_json(epub_path: &str, output_dir: &str) -> Result<JsValue, JsValu
     
     // 5. JavaScript 객체로 반환
     serde_wasm_bindgen::to_value(&epub_info)
-        .map_err(|e| JsValue::from_str(&format!("WASM 직렬화 실패: {}", e)))
+        .map_err(|e| EpubError::Serialization(e.to_string()).into())
 }
 
 /// 메모리에서 EPUB 바이트를 JSON 문자열로 변환 (파일 저장 없이)
@@ -98,12 +129,94 @@ pub fn epub_to_json(epub_path: &str, output_dir: &str) -> Result<JsValue, JsValu
 #[wasm_bindgen(js_name = epubBytesToJson)]
 pub fn epub_bytes_to_json(epub_bytes: &[u8]) -> Result<JsValue, JsValue> {
     // 메모리에서 직접 처리 (임시 파일 없음)
-    let epub_info = extract_complete_epub_info_from_bytes(epub_bytes)
-        .map_err(|e| JsValue::from_str(&format!("EPUB 파일 처리 실패: {}", e)))?;
+    let epub_info = extract_complete_epub_info_from_bytes(epub_bytes)?;
     
     // JavaScript 객체로 반환
     serde_wasm_bindgen::to_value(&epub_info)
-        .map_err(|e| JsValue::from_str(&format!("WASM 직렬화 실패: {}", e)))
+        .map_err(|e| EpubError::Serialization(e.to_string()).into())
+}
+
+/// 변환 옵션을 지정하는 [`epub_bytes_to_json`]
+/// 
+/// # JavaScript 사용법
+/// ```javascript
+/// import { epubBytesToJsonWithOptions } from 'epub-to-rn';
+/// 
+/// const result = epubBytesToJsonWithOptions(new Uint8Array(fileBuffer), {
+///   root_font_size: 18,
+///   viewport_width: 390,
+///   viewport_height: 844,
+/// });
+/// ```
+#[wasm_bindgen(js_name = epubBytesToJsonWithOptions)]
+pub fn epub_bytes_to_json_with_options(epub_bytes: &[u8], options: JsValue) -> Result<JsValue, JsValue> {
+    // 옵션을 생략하면 기본값 사용
+    let options: ConversionOptions = if options.is_undefined() || options.is_null() {
+        ConversionOptions::default()
+    } else {
+        serde_wasm_bindgen::from_value(options)
+            .map_err(|e| EpubError::InvalidOptions(e.to_string()))?
+    };
+    
+    let mut epub_info = extract_complete_epub_info_from_bytes_with_options(epub_bytes, &options)?;
+    let image_files = std::mem::take(&mut epub_info.image_files);
+    
+    let result = serde_wasm_bindgen::to_value(&epub_info)
+        .map_err(|e| EpubError::Serialization(e.to_string()))?;
+    if !image_files.is_empty() {
+        let files = image_files_to_js(image_files)?;
+        js_sys::Reflect::set(&result, &JsValue::from_str("image_files"), &files)?;
+    }
+    Ok(result)
+}
+
+/// Directory 모드의 이미지를 `{ id, path, data: Uint8Array }` 배열로 변환
+///
+/// 바이트는 serde를 거치지 않고 `Uint8Array`로 복사합니다.
+fn image_files_to_js(image_files: Vec<ImageFile>) -> Result<js_sys::Array, JsValue> {
+    let files = js_sys::Array::new();
+    for image in image_files {
+        let file = js_sys::Object::new();
+        js_sys::Reflect::set(&file, &JsValue::from_str("id"), &JsValue::from_str(&image.id))?;
+        js_sys::Reflect::set(&file, &JsValue::from_str("path"), &JsValue::from_str(&image.path))?;
+        js_sys::Reflect::set(&file, &JsValue::from_str("data"), &js_sys::Uint8Array::from(image.data.as_slice()))?;
+        files.push(&file);
+    }
+    Ok(files)
+}
+
+/// 책 전체를 변환하지 않고 표지 이미지만 추출 (없으면 `null`)
+/// 
+/// # JavaScript 사용법
+/// ```javascript
+/// import { epubCoverFromBytes } from 'epub-to-rn';
+/// 
+/// const cover = epubCoverFromBytes(new Uint8Array(fileBuffer));
+/// if (cover) console.log(cover.mime_type);
+/// ```
+#[wasm_bindgen(js_name = epubCoverFromBytes)]
+pub fn epub_cover_from_bytes(epub_bytes: &[u8]) -> Result<JsValue, JsValue> {
+    let cover = extract_cover_image_from_bytes(epub_bytes)?;
+    
+    serde_wasm_bindgen::to_value(&cover)
+        .map_err(|e| EpubError::Serialization(e.to_string()).into())
+}
+
+/// 챕터와 이미지를 변환하지 않고 메타데이터, 표지, 목차, spine만 추출 (책 목록/색인용)
+/// 
+/// # JavaScript 사용법
+/// ```javascript
+/// import { epubSummaryFromBytes } from 'epub-to-rn';
+/// 
+/// const summary = epubSummaryFromBytes(new Uint8Array(fileBuffer));
+/// console.log(summary.metadata.title, summary.toc.length);
+/// ```
+#[wasm_bindgen(js_name = epubSummaryFromBytes)]
+pub fn epub_summary_from_bytes(epub_bytes: &[u8]) -> Result<JsValue, JsValue> {
+    let summary = extract_epub_summary_from_bytes(epub_bytes)?;
+    
+    serde_wasm_bindgen::to_value(&summary)
+        .map_err(|e| EpubError::Serialization(e.to_string()).into())
 }
 
 /// 간단한 버전: EPUB 경로를 받아서 JSON 문자열만 반환
@@ -118,9 +231,8 @@ pub fn epub_bytes_to_json(epub_bytes: &[u8]) -> Result<JsValue, JsValue> {
 #[wasm_bindgen(js_name = epubToJsonString)]
 pub fn epub_to_json_string(epub_path: &str) -> Result<String, JsValue> {
     // WASM에서는 파일 존재 확인을 생략하고 바로 처리 시도
-    let epub_info = extract_complete_epub_info(epub_path)
-        .map_err(|e| JsValue::from_str(&format!("EPUB 파일 처리 실패: {}", e)))?;
+    let epub_info = extract_complete_epub_info(epub_path)?;
     
     serde_json::to_string_pretty(&epub_info)
-        .map_err(|e| JsValue::from_str(&format!("JSON 직렬화 실패: {}", e)))
+        .map_err(|e| EpubError::from(e).into())
 } 
\ No newline at end of file
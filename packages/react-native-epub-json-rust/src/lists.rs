@@ -0,0 +1,200 @@
+use scraper::ElementRef;
+
+/// 목록의 항목(`<li>`) 번호를 항목 순서대로 계산
+///
+/// `<ol start>`(없으면 1, `reversed`면 항목 수)에서 시작해 항목마다 1씩 증가(`reversed`면 감소)하며,
+/// `<li value>`가 있으면 그 항목부터 번호를 다시 매깁니다.
+pub fn list_item_ordinals(list: ElementRef) -> Vec<i32> {
+    let items: Vec<ElementRef> = list.children()
+        .filter_map(ElementRef::wrap)
+        .filter(|child| child.value().name() == "li")
+        .collect();
+
+    let integer = |element: &ElementRef, name: &str| element.value().attr(name).and_then(|value| value.trim().parse::<i32>().ok());
+    let reversed = list.value().name() == "ol" && list.value().attr("reversed").is_some();
+    let step = if reversed { -1 } else { 1 };
+    let mut ordinal = integer(&list, "start").unwrap_or(if reversed { items.len() as i32 } else { 1 });
+
+    items.iter().map(|item| {
+        if let Some(value) = integer(item, "value") {
+            ordinal = value;
+        }
+        let current = ordinal;
+        ordinal += step;
+        current
+    }).collect()
+}
+
+/// 목록의 `type` 속성(`<ol type="a">`, `<ul type="square">`, `<li type="I">`)에 해당하는 `list-style-type`
+///
+/// 번호 유형(`1`, `a`, `A`, `i`, `I`)은 대소문자를 구분하고, 기호 유형은 구분하지 않습니다.
+pub fn list_type_attribute(element: ElementRef) -> Option<&'static str> {
+    let name = element.value().name();
+    if !matches!(name, "ol" | "ul" | "li") {
+        return None;
+    }
+    let value = element.value().attr("type")?.trim();
+    let numbered = match value {
+        "1" => Some("decimal"),
+        "a" => Some("lower-alpha"),
+        "A" => Some("upper-alpha"),
+        "i" => Some("lower-roman"),
+        "I" => Some("upper-roman"),
+        _ => None,
+    };
+    numbered.filter(|_| name != "ul").or_else(|| match value.to_ascii_lowercase().as_str() {
+        "disc" => Some("disc"),
+        "circle" => Some("circle"),
+        "square" => Some("square"),
+        "none" => Some("none"),
+        _ => None,
+    })
+}
+
+/// `list-style-type`과 번호로 목록 마커 텍스트 생성
+///
+/// 문자열 값(`"– "`)은 그대로 사용하고, `none`이면 빈 문자열입니다.
+/// 알 수 없는 유형이나 표현할 수 없는 번호(0 이하의 로마 숫자 등)는 `decimal`로 표시합니다.
+pub fn list_marker(list_style_type: &str, ordinal: i32) -> String {
+    let list_style_type = list_style_type.trim();
+    if let Some(text) = list_style_type.strip_prefix(['"', '\'']).and_then(|text| text.strip_suffix(['"', '\''])) {
+        return text.to_string();
+    }
+
+    let counter = match list_style_type.to_ascii_lowercase().as_str() {
+        "none" => return String::new(),
+        "disc" => return "•".to_string(),
+        "circle" => return "◦".to_string(),
+        "square" => return "▪".to_string(),
+        "decimal-leading-zero" if (0..10).contains(&ordinal) => Some(format!("0{}", ordinal)),
+        "lower-roman" => roman(ordinal).map(|roman| roman.to_lowercase()),
+        "upper-roman" => roman(ordinal),
+        "lower-alpha" | "lower-latin" => alphabetic(ordinal, &LATIN),
+        "upper-alpha" | "upper-latin" => alphabetic(ordinal, &LATIN).map(|alpha| alpha.to_uppercase()),
+        "lower-greek" => alphabetic(ordinal, &GREEK),
+        _ => None,
+    };
+    format!("{}.", counter.unwrap_or_else(|| ordinal.to_string()))
+}
+
+const LATIN: [char; 26] = [
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
+    'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
+];
+
+const GREEK: [char; 24] = [
+    'α', 'β', 'γ', 'δ', 'ε', 'ζ', 'η', 'θ', 'ι', 'κ', 'λ', 'μ',
+    'ν', 'ξ', 'ο', 'π', 'ρ', 'σ', 'τ', 'υ', 'φ', 'χ', 'ψ', 'ω',
+];
+
+/// 1 → a, 26 → z, 27 → aa (1 미만은 None)
+fn alphabetic(ordinal: i32, letters: &[char]) -> Option<String> {
+    if ordinal < 1 {
+        return None;
+    }
+    let base = letters.len() as i32;
+    let mut value = ordinal;
+    let mut text = Vec::new();
+    while value > 0 {
+        value -= 1;
+        text.push(letters[(value % base) as usize]);
+        value /= base;
+    }
+    Some(text.into_iter().rev().collect())
+}
+
+/// 1~3999의 로마 숫자 (대문자)
+fn roman(ordinal: i32) -> Option<String> {
+    const NUMERALS: [(i32, &str); 13] = [
+        (1000, "M"), (900, "CM"), (500, "D"), (400, "CD"), (100, "C"), (90, "XC"),
+        (50, "L"), (40, "XL"), (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I"),
+    ];
+    if !(1..4000).contains(&ordinal) {
+        return None;
+    }
+    let mut value = ordinal;
+    let mut text = String::new();
+    for (number, numeral) in NUMERALS {
+        while value >= number {
+            text.push_str(numeral);
+            value -= number;
+        }
+    }
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scraper::{Html, Selector};
+
+    fn ordinals(html: &str) -> Vec<i32> {
+        let document = Html::parse_fragment(html);
+        let list = document.select(&Selector::parse("ol, ul").unwrap()).next().unwrap();
+        list_item_ordinals(list)
+    }
+
+    fn type_attribute(html: &str) -> Option<&'static str> {
+        let document = Html::parse_fragment(html);
+        let element = document.select(&Selector::parse("ol, ul, li").unwrap()).next().unwrap();
+        list_type_attribute(element)
+    }
+
+    #[test]
+    fn numbers_items_from_start() {
+        assert_eq!(ordinals("<ol><li>a</li><li>b</li><li>c</li></ol>"), [1, 2, 3]);
+        assert_eq!(ordinals(r#"<ol start="5"><li>a</li><li>b</li></ol>"#), [5, 6]);
+    }
+
+    #[test]
+    fn numbers_reversed_lists_down_from_item_count() {
+        assert_eq!(ordinals("<ol reversed><li>a</li><li>b</li><li>c</li></ol>"), [3, 2, 1]);
+        assert_eq!(ordinals(r#"<ol reversed start="10"><li>a</li><li>b</li></ol>"#), [10, 9]);
+    }
+
+    #[test]
+    fn value_renumbers_following_items() {
+        assert_eq!(ordinals(r#"<ol><li>a</li><li value="10">b</li><li>c</li></ol>"#), [1, 10, 11]);
+        assert_eq!(ordinals(r#"<ol reversed><li>a</li><li value="7">b</li><li>c</li></ol>"#), [3, 7, 6]);
+    }
+
+    #[test]
+    fn formats_roman_markers() {
+        assert_eq!(list_marker("upper-roman", 4), "IV.");
+        assert_eq!(list_marker("lower-roman", 1994), "mcmxciv.");
+        // 표현할 수 없는 번호는 decimal
+        assert_eq!(list_marker("upper-roman", 0), "0.");
+        assert_eq!(list_marker("upper-roman", 4000), "4000.");
+    }
+
+    #[test]
+    fn formats_alphabetic_markers() {
+        assert_eq!(list_marker("lower-alpha", 1), "a.");
+        assert_eq!(list_marker("upper-latin", 26), "Z.");
+        assert_eq!(list_marker("lower-alpha", 27), "aa.");
+        assert_eq!(list_marker("lower-greek", 2), "β.");
+        assert_eq!(list_marker("lower-alpha", -1), "-1.");
+    }
+
+    #[test]
+    fn formats_symbol_and_string_markers() {
+        assert_eq!(list_marker("disc", 3), "•");
+        assert_eq!(list_marker("none", 3), "");
+        assert_eq!(list_marker("\"– \"", 3), "– ");
+        assert_eq!(list_marker("decimal-leading-zero", 3), "03.");
+        assert_eq!(list_marker("unknown", 3), "3.");
+    }
+
+    #[test]
+    fn maps_type_attribute_to_list_style_type() {
+        assert_eq!(type_attribute(r#"<ol type="a"></ol>"#), Some("lower-alpha"));
+        assert_eq!(type_attribute(r#"<ol type="A"></ol>"#), Some("upper-alpha"));
+        assert_eq!(type_attribute(r#"<ol type="i"></ol>"#), Some("lower-roman"));
+        assert_eq!(type_attribute(r#"<ol type="I"></ol>"#), Some("upper-roman"));
+        assert_eq!(type_attribute(r#"<ol type="1"></ol>"#), Some("decimal"));
+        assert_eq!(type_attribute(r#"<ul type="Square"></ul>"#), Some("square"));
+        assert_eq!(type_attribute(r#"<li type="I"></li>"#), Some("upper-roman"));
+        assert_eq!(type_attribute(r#"<ul type="a"></ul>"#), None);
+        assert_eq!(type_attribute(r#"<ol type="x"></ol>"#), None);
+    }
+}
@@ -1,4 +1,4 @@
-use epub_to_rn::convert_epub_to_rn_json;
+use react_native_epub_json::convert_epub_to_json;
 use std::env;
 
 fn main() {
@@ -12,8 +12,8 @@ fn main() {
     let epub_path = &args[1];
     let output_path = &args[2];
 
-    if let Err(e) = convert_epub_to_rn_json(epub_path, output_path) {
-        eprintln!("❌ Conversion failed: {}", e);
+    if let Err(e) = convert_epub_to_json(epub_path, output_path) {
+        eprintln!("❌ Conversion failed [{}]: {}", e.code(), e);
         std::process::exit(1);
     }
 }
@@ -0,0 +1,297 @@
+use crate::types::ConversionOptions;
+use crate::css_parser::{resolve_length, LengthContext};
+
+/// `@media` 쿼리 목록을 변환 옵션의 대상 환경에 대해 평가
+///
+/// 콤마로 구분된 쿼리 중 하나라도 참이면 참입니다.
+/// 알 수 없는 미디어 타입(`amzn-kf8`, `amzn-mobi` 등)과 미디어 기능은 거짓으로 평가됩니다.
+pub fn media_query_matches(query: &str, options: &ConversionOptions) -> bool {
+    let query = query.trim();
+    if query.is_empty() {
+        return true;
+    }
+    split_top_level(query, ',').iter().any(|single| single_media_query_matches(single, options))
+}
+
+/// 쿼리 하나: `[only | not] 타입 [and 조건]` 또는 `조건`
+fn single_media_query_matches(query: &str, options: &ConversionOptions) -> bool {
+    let query = query.trim().to_ascii_lowercase();
+    let feature = |feature: &str| media_feature_matches(feature, options);
+
+    if query.starts_with('(') || query.starts_with("not (") || query.starts_with("not(") {
+        return evaluate_condition(&query, &feature);
+    }
+
+    let mut words = query.splitn(2, char::is_whitespace);
+    let mut media_type = words.next().unwrap_or_default();
+    let mut rest = words.next().unwrap_or_default().trim_start();
+    let negated = media_type == "not";
+    if negated || media_type == "only" {
+        let mut words = rest.splitn(2, char::is_whitespace);
+        media_type = words.next().unwrap_or_default();
+        rest = words.next().unwrap_or_default().trim_start();
+    }
+
+    let type_matches = media_type == "all" || media_type.eq_ignore_ascii_case(&options.media_type);
+    let condition_matches = match rest.strip_prefix("and") {
+        Some(condition) => evaluate_condition(condition, &feature),
+        None if rest.is_empty() => true,
+        // 알 수 없는 구문
+        None => return false,
+    };
+
+    (type_matches && condition_matches) != negated
+}
+
+/// 미디어 기능 하나 (괄호 안의 내용) 평가
+fn media_feature_matches(feature: &str, options: &ConversionOptions) -> bool {
+    let feature = feature.trim();
+
+    // `(min-width: 600px)` 형태
+    if let Some((name, value)) = feature.split_once(':') {
+        let (name, value) = (name.trim(), value.trim());
+        let (compare, name): (fn(f32, f32) -> bool, &str) = if let Some(name) = name.strip_prefix("min-") {
+            (|actual, expected| actual >= expected, name)
+        } else if let Some(name) = name.strip_prefix("max-") {
+            (|actual, expected| actual <= expected, name)
+        } else {
+            (|actual, expected| (actual - expected).abs() < 0.01, name)
+        };
+
+        return match name {
+            "orientation" => value == orientation(options),
+            "prefers-color-scheme" => value.eq_ignore_ascii_case(&options.color_scheme),
+            _ => match (media_feature_value(name, options), parse_feature_value(name, value, options)) {
+                (Some(actual), Some(expected)) => compare(actual, expected),
+                _ => false,
+            },
+        };
+    }
+
+    // `(width >= 600px)`, `(400px <= width < 800px)` 형태의 범위 구문
+    if let Some(result) = range_feature_matches(feature, options) {
+        return result;
+    }
+
+    // `(color)` 같은 불리언 형태
+    match feature {
+        "orientation" | "prefers-color-scheme" => true,
+        name => media_feature_value(name, options).is_some_and(|value| value != 0.0),
+    }
+}
+
+/// 대상 환경의 미디어 기능 값
+fn media_feature_value(name: &str, options: &ConversionOptions) -> Option<f32> {
+    match name {
+        "width" | "device-width" => Some(options.viewport_width),
+        "height" | "device-height" => Some(options.viewport_height),
+        "aspect-ratio" | "device-aspect-ratio" => Some(options.viewport_width / options.viewport_height),
+        "color" => Some(8.0),
+        "monochrome" | "grid" => Some(0.0),
+        _ => None,
+    }
+}
+
+/// 미디어 기능 비교 값 (길이, 비율 또는 숫자)
+fn parse_feature_value(name: &str, value: &str, options: &ConversionOptions) -> Option<f32> {
+    if name.ends_with("aspect-ratio") {
+        return match value.split_once('/') {
+            Some((width, height)) => Some(width.trim().parse::<f32>().ok()? / height.trim().parse::<f32>().ok()?),
+            None => value.parse().ok(),
+        };
+    }
+    // 미디어 쿼리의 em은 루트 폰트 크기 기준
+    resolve_length(value, &LengthContext::from_options(options))
+}
+
+fn orientation(options: &ConversionOptions) -> &'static str {
+    if options.viewport_height >= options.viewport_width { "portrait" } else { "landscape" }
+}
+
+/// 범위 구문 평가 (범위 구문이 아니면 None)
+fn range_feature_matches(feature: &str, options: &ConversionOptions) -> Option<bool> {
+    const OPERATORS: [&str; 5] = ["<=", ">=", "<", ">", "="];
+
+    // 피연산자와 연산자를 순서대로 분리
+    let mut operands = Vec::new();
+    let mut operators = Vec::new();
+    let mut rest = feature;
+    while let Some((index, operator)) = OPERATORS.iter()
+        .filter_map(|operator| rest.find(operator).map(|index| (index, *operator)))
+        .min_by_key(|(index, operator)| (*index, std::cmp::Reverse(operator.len())))
+    {
+        operands.push(rest[..index].trim());
+        operators.push(operator);
+        rest = &rest[index + operator.len()..];
+    }
+    operands.push(rest.trim());
+    if operators.is_empty() || operators.len() > 2 {
+        return None;
+    }
+
+    // 기능 이름이 있는 위치 찾기
+    let name_index = operands.iter().position(|operand| media_feature_value(operand, options).is_some())?;
+    let name = operands[name_index];
+    let actual = media_feature_value(name, options)?;
+
+    let compare = |left: f32, operator: &str, right: f32| match operator {
+        "<=" => left <= right,
+        ">=" => left >= right,
+        "<" => left < right,
+        ">" => left > right,
+        _ => (left - right).abs() < 0.01,
+    };
+
+    let mut result = true;
+    for (index, operator) in operators.iter().enumerate() {
+        let (left, right) = (operands[index], operands[index + 1]);
+        let left = if index == name_index { actual } else { parse_feature_value(name, left, options)? };
+        let right = if index + 1 == name_index { actual } else { parse_feature_value(name, right, options)? };
+        result &= compare(left, operator, right);
+    }
+    Some(result)
+}
+
+/// `not`/`and`/`or`와 괄호로 이루어진 조건식 평가 (`@media`, `@supports` 공용)
+///
+/// `leaf`는 괄호 안의 단일 조건(괄호 제외)이나 `selector(...)` 같은 함수 표기를 평가합니다.
+pub fn evaluate_condition(condition: &str, leaf: &dyn Fn(&str) -> bool) -> bool {
+    let items = split_condition_items(condition);
+
+    match items.as_slice() {
+        [] => false,
+        [not, item] if not.eq_ignore_ascii_case("not") => !evaluate_condition_item(item, leaf),
+        [first, rest @ ..] => {
+            let mut result = evaluate_condition_item(first, leaf);
+            for pair in rest.chunks(2) {
+                let [operator, item] = pair else {
+                    return false;
+                };
+                match operator.to_ascii_lowercase().as_str() {
+                    "and" => result = result && evaluate_condition_item(item, leaf),
+                    "or" => result = result || evaluate_condition_item(item, leaf),
+                    _ => return false,
+                }
+            }
+            result
+        }
+    }
+}
+
+fn evaluate_condition_item(item: &str, leaf: &dyn Fn(&str) -> bool) -> bool {
+    match item.strip_prefix('(').and_then(|inner| inner.strip_suffix(')')) {
+        Some(inner) => {
+            let inner = inner.trim();
+            let lower = inner.to_ascii_lowercase();
+            // 중첩된 조건식
+            if inner.starts_with('(') || lower.starts_with("not ") || lower.starts_with("not(") {
+                evaluate_condition(inner, leaf)
+            } else {
+                leaf(inner)
+            }
+        }
+        None => leaf(item),
+    }
+}
+
+/// 조건식을 최상위 괄호 묶음, 함수 표기, 단어로 분리
+fn split_condition_items(condition: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut depth = 0;
+    let mut start: Option<usize> = None;
+
+    for (index, ch) in condition.char_indices() {
+        match ch {
+            '(' => {
+                // 단어 바로 뒤의 괄호는 함수 표기 (`selector(...)`)
+                if depth == 0 && start.is_none() {
+                    start = Some(index);
+                }
+                depth += 1;
+            }
+            ')' => {
+                depth -= 1;
+                if depth == 0 && let Some(begin) = start.take() {
+                    items.push(&condition[begin..=index]);
+                }
+            }
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(begin) = start.take() {
+                    items.push(&condition[begin..index]);
+                }
+            }
+            _ => {
+                if depth == 0 && start.is_none() {
+                    start = Some(index);
+                }
+            }
+        }
+    }
+    if let Some(begin) = start {
+        items.push(&condition[begin..]);
+    }
+
+    items
+}
+
+/// 괄호 밖의 구분자로 분할
+fn split_top_level(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (index, ch) in text.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if c == separator && depth == 0 => {
+                parts.push(&text[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(query: &str) -> bool {
+        // 기본 옵션: 375×667 세로 화면, screen, light
+        media_query_matches(query, &ConversionOptions::default())
+    }
+
+    #[test]
+    fn evaluates_range_syntax() {
+        assert!(matches("(width >= 300px)"));
+        assert!(!matches("(width > 375px)"));
+        assert!(matches("(width <= 375px)"));
+        assert!(matches("(300px < width)"));
+        assert!(matches("(300px <= width < 400px)"));
+        assert!(!matches("(400px <= width < 800px)"));
+        assert!(matches("(20em < width < 30em)"));
+        assert!(matches("screen and (height > 600px)"));
+        assert!(matches("(aspect-ratio < 1/1)"));
+    }
+
+    #[test]
+    fn rejects_invalid_range_syntax() {
+        assert!(!matches("(width >= wide)"));
+        assert!(!matches("(300px < width < 400px < 500px)"));
+    }
+
+    #[test]
+    fn evaluates_min_max_features() {
+        assert!(matches("(min-width: 320px)"));
+        assert!(!matches("(max-width: 320px)"));
+        assert!(matches("screen and (orientation: portrait)"));
+        assert!(!matches("print"));
+        assert!(matches("not print"));
+        assert!(!matches("amzn-kf8"));
+        assert!(matches("print, (max-width: 400px)"));
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use crate::opf_parser::XmlElement;
+use crate::types::{Collection, Contributor, MetadataIdentifier, MetadataTitle};
+
+/// `refines` 메타 (대상 요소 ID → 그 요소를 수식하는 `<meta>`들)
+///
+/// EPUB3에서는 `<meta refines="#creator01" property="role">trl</meta>`처럼
+/// 다른 메타데이터 요소에 역할, 정렬 이름 등을 덧붙입니다.
+pub struct Refinements<'a> {
+    by_id: HashMap<&'a str, Vec<&'a XmlElement>>,
+}
+
+impl<'a> Refinements<'a> {
+    pub fn new(metadata: &'a XmlElement) -> Self {
+        let mut by_id: HashMap<&str, Vec<&XmlElement>> = HashMap::new();
+        for meta in metadata.children_named("meta") {
+            if let Some(target) = meta.attr("refines") {
+                by_id.entry(target.trim().trim_start_matches('#')).or_default().push(meta);
+            }
+        }
+        Refinements { by_id }
+    }
+
+    /// 요소에 대한 `property`의 값들
+    fn values(&self, element: &XmlElement, property: &str) -> Vec<&'a str> {
+        let Some(refinements) = element.attr("id").and_then(|id| self.by_id.get(id)) else {
+            return Vec::new();
+        };
+        refinements.iter()
+            .filter(|meta| meta.attr("property") == Some(property))
+            .map(|meta| meta.text())
+            .filter(|value| !value.is_empty())
+            .collect()
+    }
+
+    /// 요소에 대한 `property`의 첫 번째 값
+    fn value(&self, element: &XmlElement, property: &str) -> Option<String> {
+        self.values(element, property).first().map(|value| value.to_string())
+    }
+
+    fn display_seq(&self, element: &XmlElement) -> Option<u32> {
+        self.value(element, "display-seq").and_then(|seq| seq.parse().ok())
+    }
+}
+
+/// 이름이 일치하는 Dublin Core 요소들의 텍스트
+pub fn dc_values(metadata: &XmlElement, name: &str) -> Vec<String> {
+    metadata.children_named(name)
+        .map(|element| element.text().to_string())
+        .filter(|value| !value.is_empty())
+        .collect()
+}
+
+/// `dc:title` 목록
+pub fn parse_titles(metadata: &XmlElement, refinements: &Refinements) -> Vec<MetadataTitle> {
+    let mut titles: Vec<MetadataTitle> = metadata.children_named("title")
+        .filter(|title| !title.text().is_empty())
+        .map(|title| MetadataTitle {
+            value: title.text().to_string(),
+            title_type: refinements.value(title, "title-type"),
+            file_as: refinements.value(title, "file-as"),
+            alternate_script: refinements.value(title, "alternate-script"),
+            display_seq: refinements.display_seq(title),
+        })
+        .collect();
+    titles.sort_by_key(|title| title.display_seq.unwrap_or(u32::MAX));
+    titles
+}
+
+/// `dc:creator` 또는 `dc:contributor` 목록
+///
+/// 역할과 정렬 이름은 EPUB3 refinement를 우선하고, 없으면 EPUB2의 `opf:role`/`opf:file-as` 속성을 사용합니다.
+pub fn parse_contributors(metadata: &XmlElement, name: &str, refinements: &Refinements) -> Vec<Contributor> {
+    let mut contributors: Vec<Contributor> = metadata.children_named(name)
+        .filter(|element| !element.text().is_empty())
+        .map(|element| {
+            let mut roles: Vec<String> = refinements.values(element, "role").into_iter().map(|role| role.to_string()).collect();
+            if roles.is_empty() && let Some(role) = element.attr("role") {
+                roles.push(role.trim().to_string());
+            }
+            Contributor {
+                name: element.text().to_string(),
+                roles,
+                file_as: refinements.value(element, "file-as").or_else(|| element.attr("file-as").map(|s| s.to_string())),
+                alternate_script: refinements.value(element, "alternate-script"),
+                display_seq: refinements.display_seq(element),
+            }
+        })
+        .collect();
+    contributors.sort_by_key(|contributor| contributor.display_seq.unwrap_or(u32::MAX));
+    contributors
+}
+
+/// `dc:identifier` 목록
+pub fn parse_identifiers(metadata: &XmlElement, refinements: &Refinements) -> Vec<MetadataIdentifier> {
+    metadata.children_named("identifier")
+        .filter(|identifier| !identifier.text().is_empty())
+        .map(|identifier| MetadataIdentifier {
+            value: identifier.text().to_string(),
+            scheme: refinements.value(identifier, "identifier-type")
+                .or_else(|| identifier.attr("scheme").map(|s| s.to_string())),
+        })
+        .collect()
+}
+
+/// 컬렉션 목록: EPUB3 `belongs-to-collection`, 없으면 Calibre의 `calibre:series` 메타
+pub fn parse_collections(metadata: &XmlElement, refinements: &Refinements) -> Vec<Collection> {
+    let collections: Vec<Collection> = metadata.children_named("meta")
+        .filter(|meta| meta.attr("property") == Some("belongs-to-collection") && meta.attr("refines").is_none())
+        .filter(|meta| !meta.text().is_empty())
+        .map(|meta| Collection {
+            name: meta.text().to_string(),
+            collection_type: refinements.value(meta, "collection-type"),
+            position: refinements.value(meta, "group-position"),
+        })
+        .collect();
+    if !collections.is_empty() {
+        return collections;
+    }
+
+    match named_meta(metadata, "calibre:series") {
+        Some(series) => vec![Collection {
+            name: series,
+            collection_type: Some("series".to_string()),
+            position: named_meta(metadata, "calibre:series_index"),
+        }],
+        None => Vec::new(),
+    }
+}
+
+/// 최종 수정 시각: `dcterms:modified`, 없으면 EPUB2의 `<dc:date opf:event="modification">`
+pub fn parse_modified(metadata: &XmlElement) -> Option<String> {
+    metadata.children_named("meta")
+        .find(|meta| meta.attr("property") == Some("dcterms:modified") && meta.attr("refines").is_none())
+        .map(|meta| meta.text().to_string())
+        .or_else(|| {
+            metadata.children_named("date")
+                .find(|date| date.attr("event") == Some("modification"))
+                .map(|date| date.text().to_string())
+        })
+        .filter(|modified| !modified.is_empty())
+}
+
+/// EPUB2 형식 `<meta name="..." content="..."/>`의 값
+fn named_meta(metadata: &XmlElement, name: &str) -> Option<String> {
+    metadata.children_named("meta")
+        .find(|meta| meta.attr("name") == Some(name))
+        .and_then(|meta| meta.attr("content"))
+        .map(|content| content.trim().to_string())
+        .filter(|content| !content.is_empty())
+}
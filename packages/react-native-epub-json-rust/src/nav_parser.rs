@@ -0,0 +1,120 @@
+use scraper::{ElementRef, Html, Selector};
+use crate::opf_parser::XmlElement;
+use crate::utils::resolve_href;
+
+/// 내비게이션 문서에서 읽은 항목 (챕터로 해석되기 전)
+#[derive(Debug, Clone, Default)]
+pub struct NavEntry {
+    pub label: String,
+    /// 아카이브 경로 (프래그먼트 포함). 링크가 없는 제목 항목이면 None
+    pub href: Option<String>,
+    /// NCX `playOrder` (EPUB3 nav에는 없음)
+    pub play_order: Option<usize>,
+    /// 항목 종류: 링크의 `epub:type` (랜드마크) 또는 NCX `pageTarget`의 `type`
+    pub epub_type: Option<String>,
+    pub children: Vec<NavEntry>,
+}
+
+/// EPUB3 내비게이션 문서에서 `epub:type`이 일치하는 `<nav>`의 항목 트리 추출
+///
+/// `path`는 내비게이션 문서의 아카이브 경로이며, `href`는 이 경로 기준으로 해석됩니다.
+/// 해당 `<nav>`가 없으면 None을 반환합니다.
+pub fn parse_nav_document(html: &str, path: &str, nav_type: &str) -> Option<Vec<NavEntry>> {
+    let document = Html::parse_document(html);
+    let nav_selector = Selector::parse("nav").unwrap();
+
+    let nav = document.select(&nav_selector).find(|nav| {
+        nav.value().attr("epub:type")
+            .is_some_and(|types| types.split_whitespace().any(|t| t == nav_type))
+    })?;
+    let list = child_elements(nav, "ol").next()
+        .or_else(|| nav.select(&Selector::parse("ol").unwrap()).next())?;
+
+    Some(parse_nav_list(list, path))
+}
+
+/// `<ol>` 아래의 `<li>` 항목들
+fn parse_nav_list(list: ElementRef, path: &str) -> Vec<NavEntry> {
+    child_elements(list, "li").filter_map(|item| {
+        // 링크는 `<a>`, 링크 없는 제목은 `<span>`
+        let label_element = item.children()
+            .filter_map(ElementRef::wrap)
+            .find(|child| matches!(child.value().name(), "a" | "span"))?;
+        let href = label_element.value().attr("href").map(|href| resolve_href(path, href));
+        let children = child_elements(item, "ol").next()
+            .map(|sublist| parse_nav_list(sublist, path))
+            .unwrap_or_default();
+
+        Some(NavEntry {
+            label: collapse_whitespace(&label_element.text().collect::<String>()),
+            href,
+            play_order: None,
+            epub_type: label_element.value().attr("epub:type").map(|t| t.trim().to_string()),
+            children,
+        })
+    }).collect()
+}
+
+/// 이름이 일치하는 직속 자식 요소들
+fn child_elements<'a>(element: ElementRef<'a>, name: &'a str) -> impl Iterator<Item = ElementRef<'a>> + 'a {
+    element.children()
+        .filter_map(ElementRef::wrap)
+        .filter(move |child| child.value().name() == name)
+}
+
+/// NCX `navMap`의 `navPoint` 트리 추출
+///
+/// `path`는 NCX 파일의 아카이브 경로입니다. `navMap`이 없으면 None을 반환합니다.
+pub fn parse_ncx_nav_map(xml: &str, path: &str) -> Option<Vec<NavEntry>> {
+    let ncx = XmlElement::parse(xml)?;
+    let nav_map = ncx.child("navMap")?;
+    Some(parse_nav_points(nav_map, path))
+}
+
+fn parse_nav_points(parent: &XmlElement, path: &str) -> Vec<NavEntry> {
+    parent.children_named("navPoint").map(|point| NavEntry {
+        label: ncx_label(point),
+        href: point.child("content").and_then(|content| content.attr("src")).map(|src| resolve_href(path, src)),
+        play_order: point.attr("playOrder").and_then(|order| order.trim().parse().ok()),
+        epub_type: None,
+        children: parse_nav_points(point, path),
+    }).collect()
+}
+
+/// NCX `pageList`의 `pageTarget` 목록 추출 (인쇄본 페이지)
+///
+/// `pageList`가 없으면 None을 반환합니다.
+pub fn parse_ncx_page_list(xml: &str, path: &str) -> Option<Vec<NavEntry>> {
+    let ncx = XmlElement::parse(xml)?;
+    let page_list = ncx.child("pageList")?;
+    Some(page_list.children_named("pageTarget").map(|target| NavEntry {
+        label: ncx_label(target),
+        href: target.child("content").and_then(|content| content.attr("src")).map(|src| resolve_href(path, src)),
+        play_order: target.attr("playOrder").and_then(|order| order.trim().parse().ok()),
+        epub_type: target.attr("type").map(|t| t.trim().to_string()),
+        children: Vec::new(),
+    }).collect())
+}
+
+/// 항목 트리를 문서 순서의 평평한 목록으로 변환
+pub fn flatten_entries(entries: Vec<NavEntry>) -> Vec<NavEntry> {
+    entries.into_iter()
+        .flat_map(|mut entry| {
+            let children = std::mem::take(&mut entry.children);
+            std::iter::once(entry).chain(flatten_entries(children))
+        })
+        .collect()
+}
+
+/// `navLabel/text` 내용
+fn ncx_label(point: &XmlElement) -> String {
+    point.child("navLabel")
+        .and_then(|label| label.child("text"))
+        .map(|text| collapse_whitespace(text.text()))
+        .unwrap_or_default()
+}
+
+/// 연속된 공백을 하나로 줄이고 앞뒤 공백 제거
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
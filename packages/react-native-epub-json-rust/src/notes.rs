@@ -0,0 +1,44 @@
+use scraper::ElementRef;
+use crate::types::NoteKind;
+
+/// 요소의 구조적 의미: `epub:type` 값과 DPUB-ARIA `role` 값(`doc-` 접두사 제외)
+fn semantic_types<'a>(element: &ElementRef<'a>) -> impl Iterator<Item = &'a str> {
+    let epub_types = element.value().attr("epub:type").unwrap_or_default().split_whitespace();
+    let roles = element.value().attr("role").unwrap_or_default().split_whitespace()
+        .filter_map(|role| role.strip_prefix("doc-"));
+    epub_types.chain(roles)
+}
+
+fn has_class(element: &ElementRef, name: &str) -> bool {
+    element.value().classes().any(|class| class.eq_ignore_ascii_case(name))
+}
+
+/// 주석 참조 링크인지 여부 (`epub:type="noteref"`, `role="doc-noteref"`, EPUB 2의 `class="noteref"`)
+pub fn is_noteref(element: ElementRef) -> bool {
+    semantic_types(&element).any(|t| t == "noteref") || has_class(&element, "noteref")
+}
+
+/// 주석으로 표시된 요소의 종류 (id가 없으면 참조할 수 없으므로 None)
+pub fn note_kind(element: ElementRef) -> Option<NoteKind> {
+    element.value().id()?;
+    let kind = semantic_types(&element).find_map(|t| match t {
+        "footnote" => Some(NoteKind::Footnote),
+        "endnote" | "rearnote" => Some(NoteKind::Endnote),
+        "note" => Some(NoteKind::Note),
+        _ => None,
+    });
+    kind.or_else(|| {
+        if has_class(&element, "footnote") {
+            Some(NoteKind::Footnote)
+        } else if has_class(&element, "endnote") {
+            Some(NoteKind::Endnote)
+        } else {
+            None
+        }
+    })
+}
+
+/// 주석을 모아 둔 구역인지 여부 (`epub:type="footnotes"`/`endnotes"`/`rearnotes"`)
+pub fn is_note_section(element: ElementRef) -> bool {
+    semantic_types(&element).any(|t| matches!(t, "footnotes" | "endnotes" | "rearnotes"))
+}
@@ -0,0 +1,173 @@
+use xml::reader::{EventReader, XmlEvent};
+
+/// 간단한 XML 요소 트리 (OPF 등 패키지 문서 파싱용)
+#[derive(Debug, Clone, Default)]
+pub struct XmlElement {
+    /// 접두사를 제외한 요소 이름 (예: `dc:creator` → `creator`)
+    pub name: String,
+    pub namespace: Option<String>,
+    /// (접두사를 제외한 속성 이름, 값)
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<XmlElement>,
+    /// 직속 텍스트 내용
+    pub text: String,
+}
+
+impl XmlElement {
+    /// XML 문자열을 요소 트리로 파싱 (루트 요소 반환)
+    pub fn parse(xml: &str) -> Option<XmlElement> {
+        let mut stack: Vec<XmlElement> = Vec::new();
+
+        for event in EventReader::from_str(xml) {
+            match event.ok()? {
+                XmlEvent::StartElement { name, attributes, .. } => {
+                    stack.push(XmlElement {
+                        name: name.local_name,
+                        namespace: name.namespace,
+                        attributes: attributes.into_iter()
+                            .map(|attribute| (attribute.name.local_name, attribute.value))
+                            .collect(),
+                        ..Default::default()
+                    });
+                }
+                XmlEvent::EndElement { .. } => {
+                    let element = stack.pop()?;
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => return Some(element),
+                    }
+                }
+                XmlEvent::Characters(text) | XmlEvent::CData(text) => {
+                    if let Some(current) = stack.last_mut() {
+                        current.text.push_str(&text);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    /// 속성 값 (접두사 무시)
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes.iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// 이름이 일치하는 첫 번째 자식 요소
+    pub fn child(&self, name: &str) -> Option<&XmlElement> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    /// 이름이 일치하는 자식 요소들
+    pub fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a XmlElement> + 'a {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    /// 앞뒤 공백을 제거한 텍스트 내용
+    pub fn text(&self) -> &str {
+        self.text.trim()
+    }
+}
+
+/// OPF manifest 항목
+#[derive(Debug, Clone)]
+pub struct ManifestItem {
+    pub id: String,
+    pub href: String,
+    pub media_type: String,
+    pub properties: Option<String>,
+}
+
+/// EPUB2 `<guide>`의 `reference`
+#[derive(Debug, Clone)]
+pub struct GuideReference {
+    /// `type` 속성 (`cover`, `toc`, `text` 등)
+    pub reference_type: String,
+    pub title: Option<String>,
+    /// OPF 기준 상대 경로
+    pub href: String,
+}
+
+/// 파싱된 OPF 패키지 문서
+#[derive(Debug, Clone, Default)]
+pub struct OpfPackage {
+    /// 문서 순서대로 정렬된 manifest 항목
+    pub manifest: Vec<ManifestItem>,
+    /// `dc:identifier` 값들 (`unique-identifier`가 가리키는 식별자가 맨 앞)
+    pub identifiers: Vec<String>,
+    /// spine의 `toc` 속성 (NCX의 manifest ID)
+    pub spine_toc: Option<String>,
+    /// EPUB2 `<guide>` 항목
+    pub guide: Vec<GuideReference>,
+    /// `<metadata>` 요소
+    pub metadata: XmlElement,
+}
+
+impl OpfPackage {
+    /// OPF XML 문자열 파싱
+    pub fn parse(opf: &str) -> Option<OpfPackage> {
+        let package = XmlElement::parse(opf)?;
+
+        let manifest = package.child("manifest")
+            .map(|manifest| manifest.children_named("item").filter_map(|item| {
+                Some(ManifestItem {
+                    id: item.attr("id")?.to_string(),
+                    href: item.attr("href")?.to_string(),
+                    media_type: item.attr("media-type").unwrap_or_default().to_string(),
+                    properties: item.attr("properties").map(|s| s.to_string()),
+                })
+            }).collect())
+            .unwrap_or_default();
+
+        let unique_identifier = package.attr("unique-identifier");
+        let mut identifiers: Vec<(bool, String)> = package.child("metadata")
+            .map(|metadata| metadata.children_named("identifier")
+                .map(|identifier| (identifier.attr("id") == unique_identifier, identifier.text().to_string()))
+                .collect())
+            .unwrap_or_default();
+        identifiers.sort_by_key(|(unique, _)| !unique);
+        let identifiers = identifiers.into_iter().map(|(_, identifier)| identifier).collect();
+
+        let spine_toc = package.child("spine")
+            .and_then(|spine| spine.attr("toc"))
+            .map(|toc| toc.to_string());
+
+        let guide = package.child("guide")
+            .map(|guide| guide.children_named("reference").filter_map(|reference| {
+                Some(GuideReference {
+                    reference_type: reference.attr("type")?.trim().to_string(),
+                    title: reference.attr("title").map(|s| s.to_string()),
+                    href: reference.attr("href")?.to_string(),
+                })
+            }).collect())
+            .unwrap_or_default();
+
+        let metadata = package.child("metadata").cloned().unwrap_or_default();
+
+        Some(OpfPackage { manifest, identifiers, spine_toc, guide, metadata })
+    }
+
+    /// EPUB3 내비게이션 문서 (`properties="nav"`)
+    pub fn nav_item(&self) -> Option<&ManifestItem> {
+        self.manifest.iter().find(|item| {
+            item.properties.as_deref().is_some_and(|properties| properties.split_whitespace().any(|p| p == "nav"))
+        })
+    }
+
+    /// NCX 문서의 manifest ID (spine의 `toc` 속성, 없으면 NCX MIME 타입으로 찾음)
+    pub fn ncx_id(&self) -> Option<&str> {
+        self.spine_toc.as_deref().or_else(|| {
+            self.manifest.iter()
+                .find(|item| item.media_type == "application/x-dtbncx+xml")
+                .map(|item| item.id.as_str())
+        })
+    }
+
+    /// manifest에서 항목의 위치 (없으면 None)
+    pub fn manifest_index(&self, id: &str) -> Option<usize> {
+        self.manifest.iter().position(|item| item.id == id)
+    }
+}
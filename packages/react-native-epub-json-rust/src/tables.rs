@@ -0,0 +1,239 @@
+use scraper::ElementRef;
+use crate::cascade::compute_element_style;
+use crate::css_parser::{parse_dimension, LengthContext, Stylesheet};
+use crate::types::{RnDimension, RnNode, RnStyles, TableColumn};
+
+/// 글자 폭은 알 수 없으므로 폰트 크기의 0.6배로 근사
+const CHARACTER_WIDTH: f32 = 0.6;
+
+/// 셀의 `colspan`/`rowspan` 속성
+///
+/// 없거나 잘못된 값이면 1이며, `rowspan="0"`은 0을 그대로 돌려줘 `layout_table`에서 표 끝까지 늘어나게 합니다.
+pub fn table_span(cell: ElementRef, name: &str) -> usize {
+    let span = cell.value().attr(name).and_then(|value| value.trim().parse::<usize>().ok());
+    match (name, span) {
+        ("rowspan", Some(0)) => 0,
+        (_, Some(span)) if span > 0 => span.min(1000),
+        _ => 1,
+    }
+}
+
+/// HTML `width` 속성 (단위 없는 숫자는 px, 퍼센트 허용)
+pub fn width_attribute(element: ElementRef, context: &LengthContext) -> Option<RnDimension> {
+    element.value().attr("width")
+        .and_then(|value| parse_dimension(value, context))
+        .filter(|width| *width != RnDimension::Auto)
+}
+
+/// `<colgroup>`/`<col>`로 지정된 열 너비 (열 순서)
+///
+/// `<col>`의 CSS `width`가 `width` 속성보다 우선하며, `span`만큼 같은 너비를 반복합니다.
+pub fn column_widths(table: ElementRef, stylesheet: &Stylesheet, context: &LengthContext) -> Vec<Option<RnDimension>> {
+    let span = |element: ElementRef| element.value().attr("span")
+        .and_then(|value| value.trim().parse::<usize>().ok())
+        .filter(|span| *span > 0)
+        .unwrap_or(1)
+        .min(1000);
+    let width = |element: ElementRef| compute_element_style(element, stylesheet, None, context)
+        .and_then(|style| style.width)
+        .filter(|width| *width != RnDimension::Auto)
+        .or_else(|| width_attribute(element, context));
+
+    let mut widths = Vec::new();
+    for group in table.children().filter_map(ElementRef::wrap).filter(|child| child.value().name() == "colgroup") {
+        let columns: Vec<ElementRef> = group.children()
+            .filter_map(ElementRef::wrap)
+            .filter(|child| child.value().name() == "col")
+            .collect();
+        if columns.is_empty() {
+            widths.extend(std::iter::repeat_n(width(group), span(group)));
+        }
+        for column in columns {
+            widths.extend(std::iter::repeat_n(width(column).or_else(|| width(group)), span(column)));
+        }
+    }
+    widths
+}
+
+/// 표의 행들에서 셀의 열 위치를 정하고 열 너비 정보를 계산
+///
+/// 위 행의 rowspan이나 colspan으로 차지된 칸을 건너뛰며 각 셀의 `column`을 채우고,
+/// rowspan은 표의 남은 행 수로 제한합니다. `widths`는 `<col>`로 지정된 열 너비이며,
+/// 지정되지 않은 열은 colspan이 1인 셀 중 처음으로 너비가 지정된 셀의 값을 씁니다.
+pub fn layout_table(rows: &mut [RnNode], widths: Vec<Option<RnDimension>>, font_size: f32) -> Vec<TableColumn> {
+    let row_count = rows.len();
+    // 열마다 아래쪽 몇 행까지 rowspan으로 차지되어 있는지 (행 인덱스, 미포함)
+    let mut occupied: Vec<usize> = Vec::new();
+    let mut spans = Vec::new();
+
+    for (row_index, row) in rows.iter_mut().enumerate() {
+        let RnNode::TableRow { children, .. } = row else { continue };
+        let mut column = 0;
+        for cell in children.iter_mut() {
+            let RnNode::TableCell { column: cell_column, colspan, rowspan, children, styles, .. } = cell else { continue };
+            while occupied.get(column).is_some_and(|until| *until > row_index) {
+                column += 1;
+            }
+            let remaining = row_count - row_index;
+            *rowspan = if *rowspan == 0 { remaining } else { (*rowspan).min(remaining) };
+            *cell_column = column;
+
+            if occupied.len() < column + *colspan {
+                occupied.resize(column + *colspan, 0);
+            }
+            for until in &mut occupied[column..column + *colspan] {
+                *until = row_index + *rowspan;
+            }
+            let (min_width, max_width) = cell_width(children, styles.as_ref(), font_size);
+            spans.push((column, *colspan, styles.as_ref().and_then(|style| style.width), min_width, max_width));
+            column += *colspan;
+        }
+    }
+
+    let mut columns: Vec<TableColumn> = (0..occupied.len().max(widths.len()))
+        .map(|index| TableColumn { width: widths.get(index).copied().flatten(), min_width: 0.0, max_width: 0.0 })
+        .collect();
+    for (start, colspan, width, min_width, max_width) in &spans {
+        if *colspan != 1 {
+            continue;
+        }
+        let column = &mut columns[*start];
+        column.width = column.width.or(*width).filter(|width| *width != RnDimension::Auto);
+        column.min_width = column.min_width.max(*min_width);
+        column.max_width = column.max_width.max(*max_width);
+    }
+    // 여러 열에 걸친 셀이 걸친 열들의 합보다 넓으면 부족한 만큼 고르게 나눠 더함
+    for (start, colspan, _, min_width, max_width) in spans.into_iter().filter(|(_, colspan, ..)| *colspan > 1) {
+        let spanned = &mut columns[start..start + colspan];
+        let extra_min = (min_width - spanned.iter().map(|column| column.min_width).sum::<f32>()).max(0.0) / colspan as f32;
+        let extra_max = (max_width - spanned.iter().map(|column| column.max_width).sum::<f32>()).max(0.0) / colspan as f32;
+        for column in spanned {
+            column.min_width += extra_min;
+            column.max_width += extra_max;
+        }
+    }
+    for column in &mut columns {
+        column.max_width = column.max_width.max(column.min_width);
+    }
+    columns
+}
+
+/// 셀 내용의 (최소, 최대) 너비 추정 (좌우 padding 포함)
+fn cell_width(children: &[RnNode], styles: Option<&RnStyles>, font_size: f32) -> (f32, f32) {
+    let padding = styles.map_or(0.0, |style| {
+        [style.paddingLeft, style.paddingRight].into_iter()
+            .map(|padding| match padding {
+                Some(RnDimension::Points(points)) => points,
+                _ => 0.0,
+            })
+            .sum()
+    });
+    let (min_width, max_width) = children.iter()
+        .map(|child| content_width(child, font_size))
+        .fold((0.0f32, 0.0f32), |(min_width, max_width), (child_min, child_max)| (min_width.max(child_min), max_width + child_max));
+    (min_width + padding, max_width + padding)
+}
+
+/// 노드 내용의 (최소, 최대) 너비 추정
+///
+/// 최소는 가장 긴 단어(이미지는 그 너비), 최대는 모든 내용을 한 줄로 늘어놓은 너비입니다.
+fn content_width(node: &RnNode, font_size: f32) -> (f32, f32) {
+    match node {
+        RnNode::Text { content, styles, .. } => {
+            let character = styles.as_ref().and_then(|style| style.fontSize).unwrap_or(font_size) * CHARACTER_WIDTH;
+            let longest_word = content.split_whitespace().map(|word| word.chars().count()).max().unwrap_or(0);
+            (longest_word as f32 * character, content.chars().count() as f32 * character)
+        }
+        RnNode::Image { width, .. } => {
+            let width = width.unwrap_or(0.0);
+            (width, width)
+        }
+        RnNode::Table { columns, .. } => (
+            columns.iter().map(|column| column.min_width).sum(),
+            columns.iter().map(|column| column.max_width).sum(),
+        ),
+        _ => cell_width(node.children(), None, font_size),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 글자 수 `text`인 셀 (폰트 크기 10이면 글자당 6px)
+    fn cell(text: &str, colspan: usize, rowspan: usize) -> RnNode {
+        RnNode::TableCell {
+            header: false,
+            column: 0,
+            colspan,
+            rowspan,
+            children: vec![RnNode::Text { content: text.to_string(), styles: None, id: None }],
+            styles: None,
+            id: None,
+        }
+    }
+
+    fn row(cells: Vec<RnNode>) -> RnNode {
+        RnNode::TableRow { header: false, children: cells, styles: None, id: None }
+    }
+
+    /// 행별 셀의 (열, rowspan)
+    fn placement(rows: &[RnNode]) -> Vec<Vec<(usize, usize)>> {
+        rows.iter()
+            .map(|row| row.children().iter()
+                .map(|cell| match cell {
+                    RnNode::TableCell { column, rowspan, .. } => (*column, *rowspan),
+                    _ => unreachable!(),
+                })
+                .collect())
+            .collect()
+    }
+
+    #[test]
+    fn places_cells_around_spans() {
+        // | A | B   B |
+        // | A | C | D |
+        // | E   E   E |
+        let mut rows = vec![
+            row(vec![cell("A", 1, 2), cell("B", 2, 1)]),
+            row(vec![cell("C", 1, 1), cell("D", 1, 1)]),
+            row(vec![cell("E", 3, 1)]),
+        ];
+        let columns = layout_table(&mut rows, Vec::new(), 10.0);
+        assert_eq!(placement(&rows), [vec![(0, 2), (1, 1)], vec![(1, 1), (2, 1)], vec![(0, 1)]]);
+        assert_eq!(columns.len(), 3);
+    }
+
+    #[test]
+    fn clamps_rowspan_to_remaining_rows() {
+        let mut rows = vec![
+            row(vec![cell("A", 1, 5), cell("B", 1, 0)]),
+            row(vec![cell("C", 1, 1)]),
+        ];
+        layout_table(&mut rows, Vec::new(), 10.0);
+        // rowspan="0"은 표 끝까지, 표보다 긴 rowspan은 남은 행 수로 제한
+        assert_eq!(placement(&rows), [vec![(0, 2), (1, 2)], vec![(2, 1)]]);
+    }
+
+    #[test]
+    fn measures_columns_from_single_column_cells() {
+        let mut rows = vec![
+            row(vec![cell("ab cdef", 1, 1), cell("a", 1, 1)]),
+            row(vec![cell("abc", 1, 1), cell("ab", 1, 1)]),
+        ];
+        let columns = layout_table(&mut rows, vec![None, Some(RnDimension::Percent(40.0))], 10.0);
+        assert_eq!(columns[0], TableColumn { width: None, min_width: 24.0, max_width: 42.0 });
+        assert_eq!(columns[1], TableColumn { width: Some(RnDimension::Percent(40.0)), min_width: 12.0, max_width: 12.0 });
+    }
+
+    #[test]
+    fn spreads_spanning_cell_width_over_its_columns() {
+        let mut rows = vec![
+            row(vec![cell("aaaaaaaaaa", 2, 1)]),
+            row(vec![cell("a", 1, 1), cell("a", 1, 1)]),
+        ];
+        let columns = layout_table(&mut rows, Vec::new(), 10.0);
+        // 60px짜리 셀이 6px 열 두 개에 걸치면 모자라는 48px을 반씩 더함
+        assert_eq!(columns.iter().map(|column| column.min_width).collect::<Vec<_>>(), [30.0, 30.0]);
+    }
+}
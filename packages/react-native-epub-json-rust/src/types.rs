@@ -4,12 +4,101 @@ use std::collections::HashMap;
 #[derive(Serialize, Deserialize, Debug)]
 pub struct CompleteEpubInfo {
     pub metadata: EpubMetadata,
+    pub cover: Option<CoverImage>,         // 표지 이미지
     pub structure: EpubStructure,
     pub toc: Vec<TocItem>,
+    pub landmarks: Vec<Landmark>,          // 주요 위치 (표지, 본문 시작 등)
+    pub page_list: Vec<PageTarget>,        // 인쇄본 페이지 위치
     pub spine: Vec<SpineItemInfo>,
     pub styles: HashMap<String, RnStyles>, // CSS를 RN 스타일로 변환
-    pub images: HashMap<String, String>,   // 이미지 ID -> base64 data URI
+    pub images: HashMap<String, String>,   // 이미지 ID -> base64 data URI (Directory 모드에서는 URL)
+    pub assets: HashMap<String, ImageAsset>, // 이미지 ID -> 파일 정보
+    pub fonts: Vec<EmbeddedFont>,          // @font-face로 내장된 폰트
     pub chapters: Vec<ChapterStructure>,   // HTML을 RN 노드 구조로 변환
+    /// 변환을 중단하지 않고 건너뛴 문제 (읽을 수 없는 챕터, 스타일시트, 패키지 문서)
+    #[serde(default)]
+    pub warnings: Vec<ConversionWarning>,
+    /// Directory 모드에서 파일로 쓸 이미지 (쓴 뒤에는 비움, [`crate::utils::save_image_files`] 참고)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub image_files: Vec<ImageFile>,
+}
+
+/// 변환 중 건너뛴 문제
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConversionWarning {
+    /// 오류 코드 ([`crate::error::EpubError::code`]와 같은 값)
+    pub code: String,
+    pub message: String,
+}
+
+/// 책 목록/색인용 요약 ([`CompleteEpubInfo`]에서 챕터, 스타일, 이미지, 폰트를 뺀 것)
+///
+/// 챕터를 변환하지 않으므로 `toc`의 `anchor`는 챕터에 실제로 있는지 확인하지 않은 프래그먼트입니다.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EpubSummary {
+    pub metadata: EpubMetadata,
+    pub cover: Option<CoverImage>,
+    pub structure: EpubStructure,
+    pub toc: Vec<TocItem>,
+    pub spine: Vec<SpineItemInfo>,
+}
+
+/// 표지 이미지
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CoverImage {
+    /// manifest ID (`images`의 키)
+    pub id: String,
+    /// 아카이브 경로
+    pub path: String,
+    pub mime_type: String,
+    /// base64 data URI (Directory 모드로 책 전체를 변환하면 `images`와 같은 이미지 URL)
+    pub source: String,
+}
+
+/// 이미지 리소스 정보 (`assets`)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImageAsset {
+    /// 아카이브 경로 (퍼센트 디코딩)
+    pub path: String,
+    pub mime_type: String,
+    /// 바이트 크기
+    pub size: usize,
+    /// 고유 너비 (px, 헤더에서 읽을 수 없으면 None)
+    pub width: Option<f32>,
+    /// 고유 높이 (px)
+    pub height: Option<f32>,
+    /// 너비 / 높이
+    pub aspect_ratio: Option<f32>,
+    /// Directory 모드에서 이미지를 쓴 파일 경로
+    pub file: Option<String>,
+}
+
+/// Directory 모드에서 파일로 쓸 이미지
+///
+/// WASM에서는 파일 시스템에 쓸 수 없으므로 변환 결과에 바이트를 담아 두고, 호출한 쪽에서 씁니다.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImageFile {
+    /// 이미지 ID
+    pub id: String,
+    /// 이미지 디렉터리 기준 상대 경로 (아카이브 경로, 퍼센트 디코딩)
+    pub path: String,
+    /// 이미지 바이트 (JSON에는 넣지 않음, WASM에서는 `Uint8Array`로 따로 전달)
+    #[serde(skip)]
+    pub data: Vec<u8>,
+}
+
+/// `@font-face`로 내장된 폰트 (난독화 해제됨)
+///
+/// 스타일의 `fontFamily`는 CSS 패밀리 이름 대신 `name`을 가리키므로,
+/// 앱에서 `source`를 `name`으로 로드하면 그대로 렌더링됩니다.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EmbeddedFont {
+    pub name: String,      // 로드 이름 (패밀리/두께/스타일별로 고유)
+    pub family: String,    // CSS의 font-family 이름
+    pub weight: String,    // "normal" | "bold" | "100" ~ "900"
+    pub style: String,     // "normal" | "italic" | "oblique"
+    pub mime_type: String,
+    pub source: String,    // base64 data URI
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -23,6 +112,62 @@ pub struct EpubMetadata {
     pub identifier: Option<String>,
     pub rights: Option<String>,
     pub subject: Option<String>,
+    /// 모든 `dc:title` (`display-seq` 순)
+    pub titles: Vec<MetadataTitle>,
+    /// 모든 `dc:creator` (`display-seq` 순)
+    pub creators: Vec<Contributor>,
+    /// 모든 `dc:contributor` (`display-seq` 순)
+    pub contributors: Vec<Contributor>,
+    pub subjects: Vec<String>,
+    pub languages: Vec<String>,
+    pub identifiers: Vec<MetadataIdentifier>,
+    /// 시리즈/세트 (`belongs-to-collection`, 없으면 `calibre:series`)
+    pub collections: Vec<Collection>,
+    /// 최종 수정 시각 (`dcterms:modified`)
+    pub modified: Option<String>,
+}
+
+/// `dc:title`과 그 refinement
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MetadataTitle {
+    pub value: String,
+    /// `main`, `subtitle`, `short`, `collection`, `edition`, `expanded`
+    pub title_type: Option<String>,
+    /// 정렬용 이름
+    pub file_as: Option<String>,
+    /// 다른 문자로 표기한 제목 (예: 한자 제목의 한글 표기)
+    pub alternate_script: Option<String>,
+    pub display_seq: Option<u32>,
+}
+
+/// `dc:creator`/`dc:contributor`와 그 refinement
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Contributor {
+    pub name: String,
+    /// MARC relator 코드 (`aut`, `trl`, `ill`, `edt` 등)
+    pub roles: Vec<String>,
+    /// 정렬용 이름 (예: `Kafka, Franz`)
+    pub file_as: Option<String>,
+    pub alternate_script: Option<String>,
+    pub display_seq: Option<u32>,
+}
+
+/// `dc:identifier`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MetadataIdentifier {
+    pub value: String,
+    /// 식별자 체계 (`ISBN`, `UUID` 또는 ONIX 코드 `15` 등)
+    pub scheme: Option<String>,
+}
+
+/// 책이 속한 컬렉션 (시리즈 등)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Collection {
+    pub name: String,
+    /// `series` 또는 `set`
+    pub collection_type: Option<String>,
+    /// 컬렉션 안에서의 순서 (예: `2`, `1.5`)
+    pub position: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -34,8 +179,40 @@ pub struct EpubStructure {
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct TocItem {
+    pub label: String,
+    /// 아카이브 경로 (프래그먼트 포함). 링크가 없는 제목 항목이면 빈 문자열
+    pub content_path: String,
+    /// `chapters` 배열에서 이 항목이 가리키는 챕터의 인덱스 (챕터가 아니면 None)
+    pub chapter_index: Option<usize>,
+    /// 챕터 안에서 이동할 요소 ID (`content_path`의 프래그먼트, 챕터에 없는 ID면 None)
+    pub anchor: Option<String>,
+    /// 중첩 깊이 (최상위 항목은 0)
+    pub depth: usize,
+    /// 목차 전체에서의 읽기 순서 (NCX `playOrder`, 없으면 문서 순서로 1부터)
+    pub play_order: usize,
+    /// 하위 항목 (부 → 장 → 절)
+    pub children: Vec<TocItem>,
+}
+
+/// 랜드마크 (EPUB3 `<nav epub:type="landmarks">`, 없으면 EPUB2 `<guide>`)
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Landmark {
+    /// EPUB3 구조 어휘 (`cover`, `toc`, `bodymatter` 등). `<guide>`의 `text`는 `bodymatter`로 바뀜
+    pub landmark_type: String,
+    pub label: String,
+    pub content_path: String,
+    pub chapter_index: Option<usize>,
+    pub anchor: Option<String>,
+}
+
+/// 인쇄본 페이지 위치 (EPUB3 `<nav epub:type="page-list">`, 없으면 NCX `pageList`)
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PageTarget {
+    /// 페이지 번호 표기 (예: `143`, `xii`)
     pub label: String,
     pub content_path: String,
+    pub chapter_index: Option<usize>,
+    pub anchor: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -47,7 +224,7 @@ pub struct SpineItemInfo {
 }
 
 // React Native 스타일 구조
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[allow(non_snake_case)]
 pub struct RnStyles {
     // 텍스트 스타일
@@ -69,8 +246,17 @@ pub struct RnStyles {
     pub textTransform: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lineHeight: Option<f32>,
+    /// 단위 없는 `line-height`의 배수 (상속 시 자식의 폰트 크기로 다시 계산, JSON에는 출력하지 않음)
+    #[serde(skip)]
+    pub lineHeightMultiplier: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub textIndent: Option<f32>,
+    /// 목록 마커 유형 (`list-style-type`, 상속됨). 마커 텍스트로 변환되므로 JSON에는 출력하지 않음
+    #[serde(skip)]
+    pub listStyleType: Option<String>,
+    /// 공백 처리 방식 (`white-space`, 상속됨). 변환 시 텍스트에 반영되므로 JSON에는 출력하지 않음
+    #[serde(skip)]
+    pub whiteSpace: Option<String>,
     
     // 배경 및 색상
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -80,47 +266,47 @@ pub struct RnStyles {
     
     // 여백
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub marginTop: Option<f32>,
+    pub marginTop: Option<RnDimension>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub marginBottom: Option<f32>,
+    pub marginBottom: Option<RnDimension>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub marginLeft: Option<f32>,
+    pub marginLeft: Option<RnDimension>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub marginRight: Option<f32>,
+    pub marginRight: Option<RnDimension>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub paddingTop: Option<f32>,
+    pub paddingTop: Option<RnDimension>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub paddingBottom: Option<f32>,
+    pub paddingBottom: Option<RnDimension>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub paddingLeft: Option<f32>,
+    pub paddingLeft: Option<RnDimension>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub paddingRight: Option<f32>,
+    pub paddingRight: Option<RnDimension>,
     
     // 크기 및 레이아웃
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub width: Option<f32>,
+    pub width: Option<RnDimension>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub height: Option<f32>,
+    pub height: Option<RnDimension>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub minWidth: Option<f32>,
+    pub minWidth: Option<RnDimension>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub maxWidth: Option<f32>,
+    pub maxWidth: Option<RnDimension>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub minHeight: Option<f32>,
+    pub minHeight: Option<RnDimension>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub maxHeight: Option<f32>,
+    pub maxHeight: Option<RnDimension>,
     
     // 포지셔닝
     #[serde(skip_serializing_if = "Option::is_none")]
     pub position: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub top: Option<f32>,
+    pub top: Option<RnDimension>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bottom: Option<f32>,
+    pub bottom: Option<RnDimension>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub left: Option<f32>,
+    pub left: Option<RnDimension>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub right: Option<f32>,
+    pub right: Option<RnDimension>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub zIndex: Option<i32>,
     
@@ -144,7 +330,7 @@ pub struct RnStyles {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub flexShrink: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flexBasis: Option<f32>,
+    pub flexBasis: Option<RnDimension>,
     
     // 테두리
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -177,26 +363,303 @@ pub struct RnStyles {
     pub overflow: Option<String>,
 }
 
+/// React Native 크기 값 (포인트 숫자, `"50%"` 같은 퍼센트 문자열, 또는 `"auto"`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RnDimension {
+    Points(f32),
+    Percent(f32),
+    Auto,
+}
+
+impl Serialize for RnDimension {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            RnDimension::Points(value) => serializer.serialize_f32(*value),
+            RnDimension::Percent(value) => serializer.serialize_str(&format!("{}%", value)),
+            RnDimension::Auto => serializer.serialize_str("auto"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RnDimension {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Number(f32),
+            Text(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Number(value) => Ok(RnDimension::Points(value)),
+            Raw::Text(text) if text == "auto" => Ok(RnDimension::Auto),
+            Raw::Text(text) => text.strip_suffix('%')
+                .and_then(|value| value.trim().parse().ok())
+                .map(RnDimension::Percent)
+                .ok_or_else(|| serde::de::Error::custom(format!("invalid dimension: {}", text))),
+        }
+    }
+}
+
+/// 변환 옵션
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct ConversionOptions {
+    /// `rem` 단위와 폰트 크기 키워드의 기준이 되는 루트 폰트 크기 (px)
+    pub root_font_size: f32,
+    /// `vw`/`vh` 단위 계산에 사용할 뷰포트 너비 (px)
+    pub viewport_width: f32,
+    /// `vw`/`vh` 단위 계산에 사용할 뷰포트 높이 (px)
+    pub viewport_height: f32,
+    /// `@media` 평가에 사용할 미디어 타입 (`screen`, `print`, `amzn-kf8` 등)
+    pub media_type: String,
+    /// `@media (prefers-color-scheme)` 평가에 사용할 색상 모드 (`light` 또는 `dark`)
+    pub color_scheme: String,
+    /// 이미지 출력 방식
+    pub image_mode: ImageMode,
+    /// Directory 모드에서 이미지 URL을 만들 템플릿
+    ///
+    /// `{path}`(아카이브 경로), `{id}`(manifest ID), `{filename}`(파일명)이 치환됩니다.
+    /// 예: `images/{path}`, `https://cdn.example.com/books/42/{path}`
+    pub image_url_template: String,
+    /// 주석(각주/미주)을 본문 흐름에서 제외할지 여부 (제외해도 챕터의 `notes`에는 담김)
+    pub hide_notes: bool,
+}
+
+/// 이미지 출력 방식
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageMode {
+    /// `Image` 노드의 `source`에 base64 data URI를 직접 넣음
+    #[default]
+    Inline,
+    /// `Image` 노드의 `source`는 `images`/`assets`의 키(이미지 ID)이고, data URI는 `images`에 한 번만 담김
+    Reference,
+    /// 이미지 바이트를 `image_files`에 담고, `source`와 `images`에는 `image_url_template`로 만든 URL을 넣음
+    Directory,
+}
+
+impl Default for ConversionOptions {
+    fn default() -> Self {
+        ConversionOptions {
+            root_font_size: 16.0,
+            viewport_width: 375.0,
+            viewport_height: 667.0,
+            media_type: "screen".to_string(),
+            color_scheme: "light".to_string(),
+            image_mode: ImageMode::Inline,
+            image_url_template: "{path}".to_string(),
+            hide_notes: false,
+        }
+    }
+}
+
 // React Native 컴포넌트 노드 구조
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(tag = "type")]
 pub enum RnNode {
     Text { 
         content: String, 
-        styles: Option<RnStyles> 
+        styles: Option<RnStyles>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String> // HTML id 속성 (앵커)
     },
     View { 
         children: Vec<RnNode>, 
-        styles: Option<RnStyles> 
+        styles: Option<RnStyles>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String> // HTML id 속성 (앵커)
     },
     Image { 
-        source: String,  // base64 data URI
+        source: String,  // base64 data URI (Reference 모드에서는 이미지 ID, Directory 모드에서는 URL)
         alt: Option<String>,
-        styles: Option<RnStyles> 
+        width: Option<f32>,  // HTML width 속성, 없으면 고유 크기 (px)
+        height: Option<f32>, // HTML height 속성, 없으면 고유 크기 (px)
+        aspect_ratio: Option<f32>, // 너비 / 높이
+        styles: Option<RnStyles>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String> // HTML id 속성 (앵커)
+    },
+    /// 인라인 서식이 섞인 텍스트 (RN에서는 자식을 중첩한 `<Text>`로 렌더링되어 한 문단으로 줄바꿈됨)
+    ///
+    /// 자식은 `Text`, `Span`, `Link`, `Image` 같은 인라인 노드입니다.
+    Span {
+        children: Vec<RnNode>,
+        styles: Option<RnStyles>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String> // HTML id 속성 (앵커)
     },
     ScrollView { 
         children: Vec<RnNode>, 
-        styles: Option<RnStyles> 
+        styles: Option<RnStyles>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String> // HTML id 속성 (앵커)
+    },
+    /// `<ul>`/`<ol>` (항목은 `ListItem` 노드)
+    List {
+        ordered: bool,
+        /// 마커 영역의 너비 (px). 항목 내용은 이만큼 들여써서 마커가 내어쓰기됨
+        indent: f32,
+        children: Vec<RnNode>,
+        styles: Option<RnStyles>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String> // HTML id 속성 (앵커)
+    },
+    /// `<li>`
+    ListItem {
+        /// 마커 텍스트 (`•`, `1.`, `iv.` 등, `list-style-type: none`이면 빈 문자열)
+        marker: String,
+        /// 마커에 적용할 텍스트 스타일
+        marker_styles: Option<Box<RnStyles>>,
+        children: Vec<RnNode>,
+        styles: Option<RnStyles>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String> // HTML id 속성 (앵커)
+    },
+    /// `<table>` (자식은 선택적인 `TableCaption` 하나와 `TableRow` 노드들)
+    Table {
+        /// 열별 너비 정보 (길이가 곧 열 수)
+        columns: Vec<TableColumn>,
+        children: Vec<RnNode>,
+        styles: Option<RnStyles>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String> // HTML id 속성 (앵커)
+    },
+    /// `<caption>`
+    TableCaption {
+        children: Vec<RnNode>,
+        styles: Option<RnStyles>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String> // HTML id 속성 (앵커)
+    },
+    /// `<tr>` (셀은 `TableCell` 노드)
+    TableRow {
+        /// `<thead>` 안의 행인지 여부
+        header: bool,
+        children: Vec<RnNode>,
+        styles: Option<RnStyles>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String> // HTML id 속성 (앵커)
+    },
+    /// `<td>`/`<th>`
+    TableCell {
+        /// `<th>`인지 여부
+        header: bool,
+        /// 셀이 시작하는 열 (0부터, 위 행의 rowspan으로 차지된 칸은 건너뜀)
+        column: usize,
+        colspan: usize,
+        /// 표의 남은 행 수를 넘지 않음
+        rowspan: usize,
+        children: Vec<RnNode>,
+        styles: Option<RnStyles>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String> // HTML id 속성 (앵커)
+    },
+    Link {
+        target: LinkTarget,
+        children: Vec<RnNode>,
+        styles: Option<RnStyles>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String> // HTML id 속성 (앵커)
+    },
+    /// 주석 참조 (`epub:type="noteref"` 등). 주석 내용은 대상 챕터의 `notes`에서 `fragment`로 찾음
+    NoteRef {
+        /// 주석 위치 (항상 `Internal`)
+        target: LinkTarget,
+        children: Vec<RnNode>,
+        styles: Option<RnStyles>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String> // HTML id 속성 (앵커)
+    },
+}
+
+impl RnNode {
+    /// 노드의 HTML id
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            RnNode::Text { id, .. }
+            | RnNode::View { id, .. }
+            | RnNode::Image { id, .. }
+            | RnNode::Span { id, .. }
+            | RnNode::ScrollView { id, .. }
+            | RnNode::List { id, .. }
+            | RnNode::ListItem { id, .. }
+            | RnNode::Table { id, .. }
+            | RnNode::TableCaption { id, .. }
+            | RnNode::TableRow { id, .. }
+            | RnNode::TableCell { id, .. }
+            | RnNode::Link { id, .. }
+            | RnNode::NoteRef { id, .. } => id.as_deref(),
+        }
+    }
+
+    /// 자식 노드들 (자식이 없는 노드는 빈 슬라이스)
+    pub fn children(&self) -> &[RnNode] {
+        match self {
+            RnNode::View { children, .. }
+            | RnNode::Span { children, .. }
+            | RnNode::ScrollView { children, .. }
+            | RnNode::List { children, .. }
+            | RnNode::ListItem { children, .. }
+            | RnNode::Table { children, .. }
+            | RnNode::TableCaption { children, .. }
+            | RnNode::TableRow { children, .. }
+            | RnNode::TableCell { children, .. }
+            | RnNode::Link { children, .. }
+            | RnNode::NoteRef { children, .. } => children,
+            RnNode::Text { .. } | RnNode::Image { .. } => &[],
+        }
+    }
+
+    /// 자식 노드들 (수정용)
+    pub fn children_mut(&mut self) -> &mut [RnNode] {
+        match self {
+            RnNode::View { children, .. }
+            | RnNode::Span { children, .. }
+            | RnNode::ScrollView { children, .. }
+            | RnNode::List { children, .. }
+            | RnNode::ListItem { children, .. }
+            | RnNode::Table { children, .. }
+            | RnNode::TableCaption { children, .. }
+            | RnNode::TableRow { children, .. }
+            | RnNode::TableCell { children, .. }
+            | RnNode::Link { children, .. }
+            | RnNode::NoteRef { children, .. } => children,
+            RnNode::Text { .. } | RnNode::Image { .. } => &mut [],
+        }
+    }
+}
+
+/// 표의 열 너비 정보
+///
+/// `min_width`/`max_width`는 colspan이 1인 셀 내용으로 추정한 값(px, 셀 좌우 padding 포함)으로,
+/// 각각 가장 긴 단어가 들어가는 너비와 줄바꿈 없이 한 줄로 늘어놓은 너비입니다.
+/// 여러 열에 걸친 셀이 더 넓으면 부족한 만큼 걸친 열들에 나눠 더합니다.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TableColumn {
+    /// `<col>` 또는 셀에 지정된 너비 (없으면 null)
+    pub width: Option<RnDimension>,
+    pub min_width: f32,
+    pub max_width: f32,
+}
+
+/// 링크 대상 (`<a href>`를 해석한 결과)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "kind")]
+pub enum LinkTarget {
+    /// 책 안의 문서: spine 인덱스와 프래그먼트 ID (`#` 제외)
+    Internal {
+        spine_index: usize,
+        fragment: Option<String>,
+    },
+    /// 외부 URL (`http:`, `https:` 등)
+    External {
+        url: String,
+    },
+    /// `mailto:` 링크 (`url`은 원래 href, `address`는 수신 주소)
+    Mailto {
+        url: String,
+        address: String,
     },
 }
 
@@ -206,4 +669,37 @@ pub struct ChapterStructure {
     pub idref: String,
     pub title: Option<String>,
     pub content: RnNode, // HTML을 RN 노드 구조로 변환
+    pub anchors: Vec<ChapterAnchor>, // id가 있는 노드 목록 (문서 순서)
+    pub unresolved_references: Vec<String>, // manifest에서 찾지 못한 이미지 참조 (원래 src)
+    pub notes: Vec<Note>, // 이 챕터에 있는 주석 (문서 순서)
+}
+
+/// 각주/미주 내용
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Note {
+    /// 주석 요소의 HTML id (`NoteRef`의 `fragment`)
+    pub id: String,
+    pub kind: NoteKind,
+    /// 주석 요소를 변환한 노드
+    pub content: RnNode,
+}
+
+/// 주석 종류
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NoteKind {
+    /// `epub:type="footnote"`, `class="footnote"`
+    Footnote,
+    /// `epub:type="endnote"`/`rearnote`, `class="endnote"`
+    Endnote,
+    /// `epub:type="note"`, 또는 표시 없이 주석 참조의 대상인 요소
+    Note,
+}
+
+/// 챕터 안의 앵커 (id가 있는 노드의 위치)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChapterAnchor {
+    pub id: String,
+    /// `content`에서 이 노드까지의 `children` 인덱스 경로 (빈 배열이면 `content` 자신)
+    pub node_path: Vec<usize>,
 } 
\ No newline at end of file
@@ -1,14 +1,34 @@
 use std::fs::File;
 use std::io::Write;
+use std::path::Path;
+use crate::error::EpubError;
+use crate::types::CompleteEpubInfo;
 
 /// JSON 문자열을 파일로 저장
-pub fn save_json_to_file(json_content: &str, file_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+pub fn save_json_to_file(json_content: &str, file_path: &str) -> Result<(), EpubError> {
     let mut file = File::create(file_path)?;
     file.write_all(json_content.as_bytes())?;
     file.flush()?;
     Ok(())
 }
 
+/// Directory 모드의 `image_files`를 `image_dir` 아래 아카이브 경로 그대로 쓰고, `assets`의 `file`을 채움
+///
+/// 쓴 뒤에는 `image_files`를 비웁니다. WASM에는 파일 시스템이 없으므로 JS 래퍼가 같은 일을 합니다.
+pub fn save_image_files(epub_info: &mut CompleteEpubInfo, image_dir: &str) -> Result<(), EpubError> {
+    for image in std::mem::take(&mut epub_info.image_files) {
+        let file = Path::new(image_dir).join(&image.path);
+        if let Some(parent) = file.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&file, &image.data)?;
+        if let Some(asset) = epub_info.assets.get_mut(&image.id) {
+            asset.file = Some(file.display().to_string());
+        }
+    }
+    Ok(())
+}
+
 /// 파일 크기를 읽기 쉬운 형태로 포맷
 pub fn format_file_size(file_path: &str) -> String {
     if let Ok(metadata) = std::fs::metadata(file_path) {
@@ -22,4 +42,105 @@ pub fn format_file_size(file_path: &str) -> String {
     } else {
         "Unknown".to_string()
     }
-} 
\ No newline at end of file
+}
+
+/// EPUB 내부 문서 기준 상대 경로를 아카이브 경로로 변환
+///
+/// `base`는 참조하는 문서의 경로 (예: `OEBPS/css/main.css`), `href`는 그 안에 적힌 참조입니다.
+/// 쿼리와 프래그먼트는 제거되고 `.`/`..` 구간은 정리됩니다.
+pub fn resolve_relative_path(base: &str, href: &str) -> String {
+    let href = href.split(['#', '?']).next().unwrap_or_default();
+
+    let mut segments: Vec<&str> = if href.starts_with('/') {
+        Vec::new()
+    } else {
+        let mut segments: Vec<&str> = base.split('/').collect();
+        segments.pop(); // 파일명 제거
+        segments
+    };
+
+    for segment in href.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+
+    segments.into_iter().filter(|segment| !segment.is_empty()).collect::<Vec<_>>().join("/")
+}
+
+/// `%XX` 퍼센트 인코딩 해제
+///
+/// manifest의 `href`와 문서 안의 참조가 서로 다르게 인코딩되어 있어도 같은 경로로 비교할 수 있게 합니다.
+/// 결과가 올바른 UTF-8이 아니면 원래 문자열을 반환합니다.
+pub fn percent_decode(text: &str) -> String {
+    if !text.contains('%') {
+        return text.to_string();
+    }
+
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let hex = bytes.get(index + 1..index + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[index], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8(decoded).unwrap_or_else(|_| text.to_string())
+}
+
+/// URL 경로에 쓸 수 없는 문자를 퍼센트 인코딩 (`/`는 유지)
+pub fn percent_encode_path(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'/' | b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+/// URL 스킴 (`https`, `mailto` 등). 상대 경로면 None
+pub fn url_scheme(href: &str) -> Option<&str> {
+    let (scheme, _) = href.split_once(':')?;
+    let mut chars = scheme.chars();
+    let valid = chars.next()?.is_ascii_alphabetic()
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    valid.then_some(scheme)
+}
+
+/// 문서 안의 참조(`href`, `src`)를 프래그먼트를 유지한 채 아카이브 경로로 변환
+///
+/// 외부 URL은 그대로 반환합니다.
+pub fn resolve_href(base: &str, href: &str) -> String {
+    let href = href.trim();
+    if url_scheme(href).is_some() {
+        return href.to_string();
+    }
+    let (path, fragment) = match href.split_once('#') {
+        Some((path, fragment)) => (path, Some(fragment)),
+        None => (href, None),
+    };
+    // `#id`만 있으면 같은 문서
+    let path = if path.is_empty() { base.to_string() } else { resolve_relative_path(base, path) };
+    match fragment {
+        Some(fragment) => format!("{}#{}", path, fragment),
+        None => path,
+    }
+}
@@ -0,0 +1,172 @@
+use std::cell::Cell;
+use scraper::ElementRef;
+use crate::types::RnNode;
+
+/// 지금까지 출력한 인라인 내용이 끝난 상태 (요소 경계를 넘어 공백을 병합하는 데 사용)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineState {
+    /// 줄의 시작 (블록의 시작과 끝, `<br>` 직후): 이어지는 공백은 버림
+    #[default]
+    LineStart,
+    /// 병합된 공백으로 끝남: 이어지는 공백은 버리고, 줄이 끝나면 이 공백도 지움
+    Space,
+    /// 공백이 아닌 내용으로 끝남
+    Content,
+}
+
+/// 블록 수준 요소인지 여부 (태그 기준이며 CSS `display`는 반영하지 않음)
+pub fn is_block_element(element: ElementRef) -> bool {
+    matches!(
+        element.value().name(),
+        "address" | "article" | "aside" | "blockquote" | "body" | "caption" | "center" | "dd" | "details"
+            | "div" | "dl" | "dt" | "fieldset" | "figcaption" | "figure" | "footer" | "form"
+            | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "header" | "hgroup" | "hr" | "html" | "legend" | "li"
+            | "main" | "nav" | "ol" | "p" | "pre" | "section" | "summary"
+            | "table" | "tbody" | "td" | "tfoot" | "th" | "thead" | "tr" | "ul"
+    )
+}
+
+/// CSS의 공백 문자 (줄바꿈 없는 공백 U+00A0은 내용으로 취급)
+fn is_css_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r' | '\x0C')
+}
+
+/// `white-space` 값에 따라 텍스트 노드의 공백 처리
+///
+/// - `normal`, `nowrap`: 연속된 공백과 줄바꿈을 공백 하나로 병합하며, 줄 시작이나 앞 내용이
+///   공백으로 끝났으면 버립니다 (앞 내용이 다른 요소에 있어도 `state`로 이어짐).
+/// - `pre-line`: 공백은 병합하고 줄바꿈은 유지합니다.
+/// - `pre`, `pre-wrap`, `break-spaces`: 공백과 줄바꿈을 모두 그대로 둡니다.
+pub fn collapse_whitespace(text: &str, white_space: Option<&str>, state: &Cell<LineState>) -> String {
+    let mut current = state.get();
+    let mut result = String::with_capacity(text.len());
+
+    match white_space.unwrap_or("normal") {
+        "pre" | "pre-wrap" | "break-spaces" => {
+            result = text.replace("\r\n", "\n");
+            if let Some(last) = result.chars().last() {
+                current = if last == '\n' { LineState::LineStart } else { LineState::Content };
+            }
+        }
+        white_space => {
+            let keep_newlines = white_space == "pre-line";
+            for c in text.chars() {
+                if keep_newlines && c == '\n' {
+                    if current == LineState::Space && result.ends_with(' ') {
+                        result.pop();
+                    }
+                    result.push('\n');
+                    current = LineState::LineStart;
+                } else if is_css_whitespace(c) {
+                    if current == LineState::Content {
+                        result.push(' ');
+                        current = LineState::Space;
+                    }
+                } else {
+                    result.push(c);
+                    current = LineState::Content;
+                }
+            }
+        }
+    }
+
+    state.set(current);
+    result
+}
+
+/// 줄 끝 처리: 마지막 텍스트의 병합된 공백을 지우고 줄 시작 상태로 전환
+///
+/// 블록 요소의 시작과 끝, `<br>` 앞에서 호출합니다.
+pub fn end_line(nodes: &mut Vec<RnNode>, state: &Cell<LineState>) {
+    if state.get() == LineState::Space {
+        trim_trailing_space(nodes);
+    }
+    state.set(LineState::LineStart);
+}
+
+/// 마지막 텍스트(인라인 래퍼 안쪽 포함)의 끝 공백 하나를 지우고, 비게 된 텍스트 노드는 제거
+fn trim_trailing_space(nodes: &mut Vec<RnNode>) -> bool {
+    let Some(last) = nodes.last_mut() else {
+        return false;
+    };
+    let trimmed = match last {
+        RnNode::Text { content, .. } => content.ends_with(' ') && content.pop().is_some(),
+        RnNode::View { children, .. }
+        | RnNode::Span { children, .. }
+        | RnNode::Link { children, .. }
+        | RnNode::NoteRef { children, .. } => {
+            trim_trailing_space(children)
+        }
+        _ => false,
+    };
+    if matches!(last, RnNode::Text { content, id: None, .. } if content.is_empty()) {
+        nodes.pop();
+    }
+    trimmed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(content: &str) -> RnNode {
+        RnNode::Text { content: content.to_string(), styles: None, id: None }
+    }
+
+    #[test]
+    fn collapses_whitespace_across_inline_boundaries() {
+        // `<p>  one <b> two</b>  three </p>`: 텍스트 노드마다 따로 호출되지만 상태를 공유
+        let state = Cell::new(LineState::LineStart);
+        assert_eq!(collapse_whitespace("  one ", None, &state), "one ");
+        assert_eq!(collapse_whitespace(" two", None, &state), "two");
+        assert_eq!(collapse_whitespace("\n\t three ", None, &state), " three ");
+        assert_eq!(state.get(), LineState::Space);
+    }
+
+    #[test]
+    fn drops_whitespace_only_text_between_inlines() {
+        let state = Cell::new(LineState::LineStart);
+        assert_eq!(collapse_whitespace("a ", None, &state), "a ");
+        assert_eq!(collapse_whitespace("   ", None, &state), "");
+        assert_eq!(collapse_whitespace("b", None, &state), "b");
+    }
+
+    #[test]
+    fn keeps_non_breaking_spaces() {
+        let state = Cell::new(LineState::LineStart);
+        assert_eq!(collapse_whitespace("\u{a0} a \u{a0} b", None, &state), "\u{a0} a \u{a0} b");
+    }
+
+    #[test]
+    fn preserves_pre_and_pre_line() {
+        let state = Cell::new(LineState::Content);
+        assert_eq!(collapse_whitespace("  a\n  b\n", Some("pre"), &state), "  a\n  b\n");
+        assert_eq!(state.get(), LineState::LineStart);
+
+        let state = Cell::new(LineState::LineStart);
+        assert_eq!(collapse_whitespace("a  \n   b", Some("pre-line"), &state), "a\nb");
+    }
+
+    #[test]
+    fn end_line_trims_trailing_space_inside_inline_wrappers() {
+        let state = Cell::new(LineState::LineStart);
+        let content = collapse_whitespace("two ", None, &state);
+        let mut nodes = vec![
+            text("one "),
+            RnNode::Span { children: vec![text(&content)], styles: None, id: None },
+        ];
+        end_line(&mut nodes, &state);
+
+        assert_eq!(state.get(), LineState::LineStart);
+        let RnNode::Span { children, .. } = &nodes[1] else { panic!("expected Span") };
+        assert!(matches!(&children[0], RnNode::Text { content, .. } if content == "two"));
+    }
+
+    #[test]
+    fn end_line_removes_text_left_empty() {
+        let state = Cell::new(LineState::Space);
+        let mut nodes = vec![text("a"), text(" ")];
+        end_line(&mut nodes, &state);
+        assert_eq!(nodes.len(), 1);
+    }
+}
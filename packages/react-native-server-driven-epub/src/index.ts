@@ -4,12 +4,71 @@ import * as wasm from './lib/react_native_epub_json';
 
 export interface CompleteEpubInfo {
   metadata: EpubMetadata;
+  cover?: CoverImage;
   structure: EpubStructure;
   toc: TocItem[];
+  landmarks: Landmark[];
+  page_list: PageTarget[];
   spine: SpineItemInfo[];
   styles: Record<string, RnStyles>;
   images: Record<string, string>;
+  assets: Record<string, ImageAsset>;
+  fonts: EmbeddedFont[];
   chapters: ChapterStructure[];
+  /** Problems that were skipped without aborting the conversion. */
+  warnings: ConversionWarning[];
+}
+
+/**
+ * A chapter, stylesheet or package document the converter skipped.
+ */
+export interface ConversionWarning {
+  code: EpubErrorCode;
+  message: string;
+}
+
+/**
+ * An image resource in the asset manifest, keyed by image id.
+ */
+export interface ImageAsset {
+  /** Path inside the EPUB archive. */
+  path: string;
+  mime_type: string;
+  /** Size in bytes. */
+  size: number;
+  /** Intrinsic size in px, read from the image header. */
+  width?: number;
+  height?: number;
+  /** Width divided by height. */
+  aspect_ratio?: number;
+  /** File the image was written to in `directory` image mode. */
+  file?: string;
+}
+
+/**
+ * Lightweight summary for library indexing: `CompleteEpubInfo` without
+ * chapters, styles, images or fonts. Chapters are not converted, so TOC
+ * anchors are the raw fragments and are not checked against the chapter.
+ */
+export interface EpubSummary {
+  metadata: EpubMetadata;
+  cover?: CoverImage;
+  structure: EpubStructure;
+  toc: TocItem[];
+  spine: SpineItemInfo[];
+}
+
+/**
+ * A font embedded through `@font-face`, with any obfuscation removed.
+ * `fontFamily` in node styles refers to `name`.
+ */
+export interface EmbeddedFont {
+  name: string;
+  family: string;
+  weight: string;
+  style: string;
+  mime_type: string;
+  source: string;
 }
 
 export interface EpubMetadata {
@@ -22,6 +81,68 @@ export interface EpubMetadata {
   identifier?: string;
   rights?: string;
   subject?: string;
+  /** All titles, ordered by display sequence. */
+  titles: MetadataTitle[];
+  /** All creators, ordered by display sequence. */
+  creators: Contributor[];
+  /** Translators, illustrators, editors and other contributors. */
+  contributors: Contributor[];
+  subjects: string[];
+  languages: string[];
+  identifiers: MetadataIdentifier[];
+  /** Series or sets the book belongs to. */
+  collections: Collection[];
+  /** Last modification timestamp (`dcterms:modified`). */
+  modified?: string;
+}
+
+export interface MetadataTitle {
+  value: string;
+  /** `main`, `subtitle`, `short`, `collection`, `edition` or `expanded`. */
+  title_type?: string;
+  file_as?: string;
+  alternate_script?: string;
+  display_seq?: number;
+}
+
+export interface Contributor {
+  name: string;
+  /** MARC relator codes such as `aut`, `trl`, `ill` or `edt`. */
+  roles: string[];
+  /** Sort name, e.g. `Kafka, Franz`. */
+  file_as?: string;
+  alternate_script?: string;
+  display_seq?: number;
+}
+
+export interface MetadataIdentifier {
+  value: string;
+  /** Identifier scheme, e.g. `ISBN` or an ONIX code such as `15`. */
+  scheme?: string;
+}
+
+export interface Collection {
+  name: string;
+  /** `series` or `set`. */
+  collection_type?: string;
+  /** Position within the collection, e.g. `2`. */
+  position?: string;
+}
+
+/**
+ * Cover image, detected from the package document without guessing image ids.
+ */
+export interface CoverImage {
+  /** Manifest id, also the key in `images`. */
+  id: string;
+  /** Path inside the EPUB archive. */
+  path: string;
+  mime_type: string;
+  /**
+   * Base64 data URI, or the image URL when a whole book is converted in
+   * `directory` image mode.
+   */
+  source: string;
 }
 
 export interface EpubStructure {
@@ -32,7 +153,40 @@ export interface EpubStructure {
 
 export interface TocItem {
   label: string;
+  /** Archive path with fragment; empty for heading-only entries. */
   content_path: string;
+  /** Index into `chapters` of the chapter this entry points at. */
+  chapter_index?: number;
+  /** Element id to scroll to within the chapter. */
+  anchor?: string;
+  /** Nesting depth; top-level entries are 0. */
+  depth: number;
+  /** Reading order across the whole TOC, starting at 1. */
+  play_order: number;
+  children: TocItem[];
+}
+
+/**
+ * Key location in the book, from the EPUB3 landmarks nav or the EPUB2 guide.
+ * Guide types are mapped to EPUB3 names (`text` becomes `bodymatter`).
+ */
+export interface Landmark {
+  landmark_type: string;
+  label: string;
+  content_path: string;
+  chapter_index?: number;
+  anchor?: string;
+}
+
+/**
+ * Start of a print page, from the EPUB3 page-list nav or the NCX pageList.
+ */
+export interface PageTarget {
+  /** Print page number as displayed, e.g. `"143"` or `"xii"`. */
+  label: string;
+  content_path: string;
+  chapter_index?: number;
+  anchor?: string;
 }
 
 export interface SpineItemInfo {
@@ -42,6 +196,11 @@ export interface SpineItemInfo {
   linear: boolean;
 }
 
+/**
+ * A point value, a percentage string such as `"50%"`, or `"auto"`.
+ */
+export type RnDimension = number | string;
+
 export interface RnStyles {
   // 텍스트 스타일
   fontSize?: number;
@@ -60,29 +219,29 @@ export interface RnStyles {
   opacity?: number;
 
   // 여백
-  marginTop?: number;
-  marginBottom?: number;
-  marginLeft?: number;
-  marginRight?: number;
-  paddingTop?: number;
-  paddingBottom?: number;
-  paddingLeft?: number;
-  paddingRight?: number;
+  marginTop?: RnDimension;
+  marginBottom?: RnDimension;
+  marginLeft?: RnDimension;
+  marginRight?: RnDimension;
+  paddingTop?: RnDimension;
+  paddingBottom?: RnDimension;
+  paddingLeft?: RnDimension;
+  paddingRight?: RnDimension;
 
   // 크기 및 레이아웃
-  width?: number;
-  height?: number;
-  minWidth?: number;
-  maxWidth?: number;
-  minHeight?: number;
-  maxHeight?: number;
+  width?: RnDimension;
+  height?: RnDimension;
+  minWidth?: RnDimension;
+  maxWidth?: RnDimension;
+  minHeight?: RnDimension;
+  maxHeight?: RnDimension;
 
   // 포지셔닝
   position?: string;
-  top?: number;
-  bottom?: number;
-  left?: number;
-  right?: number;
+  top?: RnDimension;
+  bottom?: RnDimension;
+  left?: RnDimension;
+  right?: RnDimension;
   zIndex?: number;
 
   // Flexbox
@@ -95,7 +254,7 @@ export interface RnStyles {
   flex?: number;
   flexGrow?: number;
   flexShrink?: number;
-  flexBasis?: number;
+  flexBasis?: RnDimension;
 
   // 테두리
   borderWidth?: number;
@@ -115,31 +274,200 @@ export interface RnStyles {
   overflow?: string;
 }
 
-export type RnNode = TextNode | ViewNode | ImageNode | ScrollViewNode;
+export type RnNode =
+  | TextNode
+  | ViewNode
+  | ImageNode
+  | SpanNode
+  | ScrollViewNode
+  | ListNode
+  | ListItemNode
+  | TableNode
+  | TableCaptionNode
+  | TableRowNode
+  | TableCellNode
+  | LinkNode
+  | NoteRefNode;
 
 export interface TextNode {
   type: 'Text';
+  /**
+   * Text with whitespace collapsed as CSS `white-space` would, kept across
+   * inline element boundaries. A `<br>` becomes a node whose content is `\n`.
+   */
   content: string;
   styles?: RnStyles;
+  id?: string;
 }
 
 export interface ViewNode {
   type: 'View';
   children: RnNode[];
   styles?: RnStyles;
+  id?: string;
 }
 
 export interface ImageNode {
   type: 'Image';
+  /**
+   * Data URI in `inline` image mode, image id (a key of `images`) in
+   * `reference` mode, or the templated URL in `directory` mode.
+   */
   source: string;
   alt?: string;
+  /** HTML `width` attribute, or the intrinsic width in px. */
+  width?: number;
+  /** HTML `height` attribute, or the intrinsic height in px. */
+  height?: number;
+  /** Width divided by height. */
+  aspect_ratio?: number;
   styles?: RnStyles;
+  id?: string;
+}
+
+/**
+ * Text with mixed inline formatting, such as a paragraph containing `<em>`
+ * or `<a>`. Render it as a `<Text>` with its children nested inside so the
+ * whole run wraps as one paragraph. Children are inline nodes: `Text`,
+ * `Span`, `Link` and `Image`.
+ */
+export interface SpanNode {
+  type: 'Span';
+  children: RnNode[];
+  styles?: RnStyles;
+  id?: string;
 }
 
 export interface ScrollViewNode {
   type: 'ScrollView';
   children: RnNode[];
   styles?: RnStyles;
+  id?: string;
+}
+
+/**
+ * An `<ul>` or `<ol>`. Its items are `ListItem` nodes.
+ */
+export interface ListNode {
+  type: 'List';
+  ordered: boolean;
+  /** Width of the marker column in px; item content is indented by it. */
+  indent: number;
+  children: RnNode[];
+  styles?: RnStyles;
+  id?: string;
+}
+
+/**
+ * An `<li>` with its marker text already resolved from `list-style-type`
+ * and the list numbering attributes.
+ */
+export interface ListItemNode {
+  type: 'ListItem';
+  /** `•`, `1.`, `iv.` and so on; empty for `list-style-type: none`. */
+  marker: string;
+  marker_styles?: RnStyles;
+  children: RnNode[];
+  styles?: RnStyles;
+  id?: string;
+}
+
+/**
+ * A `<table>`. Children are an optional `TableCaption` followed by the
+ * `TableRow` nodes of `<thead>`, `<tbody>` and `<tfoot>` in document order.
+ */
+export interface TableNode {
+  type: 'Table';
+  /** One entry per column of the table grid. */
+  columns: TableColumn[];
+  children: RnNode[];
+  styles?: RnStyles;
+  id?: string;
+}
+
+/**
+ * Width hints for a table column. `min_width` fits the longest word and
+ * `max_width` the content on a single line, both estimated in px from the
+ * cells spanning only this column, including their horizontal padding.
+ */
+export interface TableColumn {
+  /** Width from `<col>` or a cell's `width`, if any. */
+  width: RnDimension | null;
+  min_width: number;
+  max_width: number;
+}
+
+export interface TableCaptionNode {
+  type: 'TableCaption';
+  children: RnNode[];
+  styles?: RnStyles;
+  id?: string;
+}
+
+export interface TableRowNode {
+  type: 'TableRow';
+  /** Whether the row is inside `<thead>`. */
+  header: boolean;
+  children: RnNode[];
+  styles?: RnStyles;
+  id?: string;
+}
+
+export interface TableCellNode {
+  type: 'TableCell';
+  /** Whether the cell is a `<th>`. */
+  header: boolean;
+  /** First grid column, skipping slots taken by rowspans from above. */
+  column: number;
+  colspan: number;
+  /** Clamped to the rows left in the table. */
+  rowspan: number;
+  children: RnNode[];
+  styles?: RnStyles;
+  id?: string;
+}
+
+/**
+ * Target of an `<a href>`. Internal links point at a spine item and,
+ * optionally, an element id within it.
+ */
+export type LinkTarget =
+  | { kind: 'Internal'; spine_index: number; fragment?: string }
+  | { kind: 'External'; url: string }
+  | { kind: 'Mailto'; url: string; address: string };
+
+export interface LinkNode {
+  type: 'Link';
+  target: LinkTarget;
+  children: RnNode[];
+  styles?: RnStyles;
+  id?: string;
+}
+
+/**
+ * A reference to a footnote or endnote (`epub:type="noteref"`,
+ * `class="noteref"`, or any link to a note). The note body is the entry of
+ * `notes` whose `id` is `target.fragment`, in the chapter whose
+ * `spine_index` is `target.spine_index`.
+ */
+export interface NoteRefNode {
+  type: 'NoteRef';
+  target: Extract<LinkTarget, { kind: 'Internal' }>;
+  children: RnNode[];
+  styles?: RnStyles;
+  id?: string;
+}
+
+export type NoteKind = 'footnote' | 'endnote' | 'note';
+
+/**
+ * A note extracted for display in a popover. `note` is a note without
+ * markup of its own that a noteref points at.
+ */
+export interface Note {
+  id: string;
+  kind: NoteKind;
+  content: RnNode;
 }
 
 export interface ChapterStructure {
@@ -147,6 +475,54 @@ export interface ChapterStructure {
   idref: string;
   title?: string;
   content: RnNode;
+  anchors: ChapterAnchor[];
+  /** Image `src` values with no matching manifest item, as written. */
+  unresolved_references: string[];
+  /** Footnotes and endnotes in this chapter, in document order. */
+  notes: Note[];
+}
+
+/**
+ * A node carrying an HTML id, located by its child-index path from the chapter
+ * root.
+ */
+export interface ChapterAnchor {
+  id: string;
+  node_path: number[];
+}
+
+/**
+ * Error codes reported by the converter.
+ */
+export type EpubErrorCode = (typeof EPUB_ERROR_CODES)[number];
+
+const EPUB_ERROR_CODES = [
+  'ARCHIVE_ERROR',
+  'CONTAINER_ERROR',
+  'RESOURCE_MISSING',
+  'ENCODING_ERROR',
+  'CSS_ERROR',
+  'SERIALIZATION_ERROR',
+  'INVALID_OPTIONS',
+  'IO_ERROR',
+] as const;
+
+/**
+ * Error thrown when a conversion fails. Switch on `code` to handle specific failures.
+ */
+export interface EpubConversionError extends Error {
+  code: EpubErrorCode;
+}
+
+function toConversionError(error: unknown): EpubConversionError {
+  const message = error instanceof Error ? error.message : String(error);
+  const code = (error as { code?: unknown } | null)?.code;
+  // Errors from the WASM module already carry a converter code; Node.js fs errors do not
+  const converterCode =
+    EPUB_ERROR_CODES.find((known) => known === code) ?? 'IO_ERROR';
+  return Object.assign(new Error(`EPUB conversion failed: ${message}`), {
+    code: converterCode,
+  });
 }
 
 /**
@@ -175,10 +551,7 @@ export function epubToJson(
 
     return result;
   } catch (error: unknown) {
-    if (error instanceof Error) {
-      throw new Error(`EPUB conversion failed: ${error.message}`);
-    }
-    throw new Error(`EPUB conversion failed: ${String(error)}`);
+    throw toConversionError(error);
   }
 }
 
@@ -195,10 +568,7 @@ export function epubToJsonString(epub_path: string): string {
     );
     return JSON.stringify(result, null, 2);
   } catch (error: unknown) {
-    if (error instanceof Error) {
-      throw new Error(`EPUB conversion failed: ${error.message}`);
-    }
-    throw new Error(`EPUB conversion failed: ${String(error)}`);
+    throw toConversionError(error);
   }
 }
 
@@ -211,6 +581,151 @@ export function epubBytesToJson(epub_bytes: Uint8Array): CompleteEpubInfo {
   return wasm.epubBytesToJson(epub_bytes);
 }
 
+/**
+ * Options that control how relative CSS units are resolved.
+ */
+export interface ConversionOptions {
+  /** Root font size used for `rem` and keyword font sizes. Defaults to 16. */
+  root_font_size?: number;
+  /** Viewport width used for `vw`. Defaults to 375. */
+  viewport_width?: number;
+  /** Viewport height used for `vh`. Defaults to 667. */
+  viewport_height?: number;
+  /** Media type that `@media` rules are evaluated against. Defaults to `"screen"`. */
+  media_type?: string;
+  /** Value matched by `@media (prefers-color-scheme)`. Defaults to `"light"`. */
+  color_scheme?: 'light' | 'dark';
+  /**
+   * How images are emitted. Defaults to `"inline"`.
+   * - `inline`: every Image node carries the base64 data URI.
+   * - `reference`: Image nodes carry the image id; data URIs live once in
+   *   `images`.
+   * - `directory`: images are written to `image_dir` with Node.js `fs`;
+   *   nodes and `images` carry URLs built from `image_url_template`.
+   */
+  image_mode?: 'inline' | 'reference' | 'directory';
+  /** Directory to write images to in `directory` mode. Required there. */
+  image_dir?: string;
+  /**
+   * URL template for `directory` mode. `{path}` (archive path), `{id}` and
+   * `{filename}` are substituted. Defaults to `"{path}"`.
+   */
+  image_url_template?: string;
+  /**
+   * Leave notes and note sections (`epub:type="footnotes"`/`endnotes"`) out
+   * of the chapter content. Notes are still listed in `notes`. Defaults to
+   * `false`.
+   */
+  hide_notes?: boolean;
+}
+
+/**
+ * Converts EPUB bytes from memory into a JSON object with conversion options.
+ * @param epub_bytes The EPUB file content as a byte array.
+ * @param options Unit resolution and image output options.
+ * @returns The converted JSON object.
+ */
+export function epubBytesToJsonWithOptions(
+  epub_bytes: Uint8Array,
+  options?: ConversionOptions,
+): CompleteEpubInfo {
+  const image_dir = options?.image_dir;
+  if (options?.image_mode === 'directory' && !image_dir) {
+    const error = new Error(
+      'image_dir is required for the directory image mode',
+    );
+    throw toConversionError(Object.assign(error, { code: 'INVALID_OPTIONS' }));
+  }
+
+  const { image_files = [], ...result }: ConvertedEpubInfo =
+    wasm.epubBytesToJsonWithOptions(epub_bytes, options);
+  if (image_dir) {
+    try {
+      writeImageFiles(result, image_files, image_dir);
+    } catch (error: unknown) {
+      throw toConversionError(error);
+    }
+  }
+  return result;
+}
+
+/**
+ * Image bytes returned by the WASM module in `directory` mode. WASM has no
+ * file system, so the wrapper writes them.
+ */
+interface ImageFile {
+  id: string;
+  /** Path relative to `image_dir` (the archive path). */
+  path: string;
+  data: Uint8Array;
+}
+
+type ConvertedEpubInfo = CompleteEpubInfo & { image_files?: ImageFile[] };
+
+function writeImageFiles(
+  result: CompleteEpubInfo,
+  image_files: ImageFile[],
+  image_dir: string,
+): void {
+  for (const image of image_files) {
+    const file = path.join(image_dir, image.path);
+    fs.mkdirSync(path.dirname(file), { recursive: true });
+    fs.writeFileSync(file, image.data);
+    const asset = result.assets[image.id];
+    if (asset) {
+      asset.file = file;
+    }
+  }
+}
+
+/**
+ * Reads only the cover image of an EPUB file, without converting the chapters.
+ * @param epub_path Path to the EPUB file.
+ * @returns The cover image, or `null` if the book has none.
+ */
+export function epubCover(epub_path: string): CoverImage | null {
+  try {
+    const fileBuffer = fs.readFileSync(epub_path);
+    return wasm.epubCoverFromBytes(new Uint8Array(fileBuffer)) ?? null;
+  } catch (error: unknown) {
+    throw toConversionError(error);
+  }
+}
+
+/**
+ * Reads only the cover image from EPUB bytes, without converting the chapters.
+ * @param epub_bytes The EPUB file content as a byte array.
+ * @returns The cover image, or `null` if the book has none.
+ */
+export function epubCoverFromBytes(epub_bytes: Uint8Array): CoverImage | null {
+  return wasm.epubCoverFromBytes(epub_bytes) ?? null;
+}
+
+/**
+ * Reads metadata, cover, TOC and spine of an EPUB file without converting
+ * chapters or images. Use it to index many books cheaply.
+ * @param epub_path Path to the EPUB file.
+ * @returns The book summary.
+ */
+export function epubSummary(epub_path: string): EpubSummary {
+  try {
+    const fileBuffer = fs.readFileSync(epub_path);
+    return wasm.epubSummaryFromBytes(new Uint8Array(fileBuffer));
+  } catch (error: unknown) {
+    throw toConversionError(error);
+  }
+}
+
+/**
+ * Reads metadata, cover, TOC and spine from EPUB bytes without converting
+ * chapters or images.
+ * @param epub_bytes The EPUB file content as a byte array.
+ * @returns The book summary.
+ */
+export function epubSummaryFromBytes(epub_bytes: Uint8Array): EpubSummary {
+  return wasm.epubSummaryFromBytes(epub_bytes);
+}
+
 /**
  * The raw WASM module for advanced use.
  */